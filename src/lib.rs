@@ -1,6 +1,7 @@
 // src/lib.rs
 
 pub mod map;
+pub mod rl;
 pub mod specials;
 pub mod state;
 pub mod tui;
@@ -4,7 +4,7 @@ use serde::{Serialize, Deserialize};
 use super::tile::{Tile, TileType};
 use super::position::MapPosition; 
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Map {
     width: i32, 
     height: i32,
@@ -75,12 +75,26 @@ impl Map {
 
     /// Чи можна ходити (логіка тайла).
     pub fn is_walkable<P: Into<MapPosition>>(&self, pos: P) -> bool {
-        self.get_tile(pos).map_or(false, |t| t.is_walkable())
+        self.get_tile(pos).is_some_and(|t| t.is_walkable())
     }
 
     /// Чи можна стояти (логіка тайла + відсутність інших сутностей).
     pub fn is_standable<P: Into<MapPosition>>(&self, pos: P) -> bool {
-        self.get_tile(pos).map_or(false, |t| t.can_stand())
+        self.get_tile(pos).is_some_and(|t| t.can_stand())
+    }
+
+    /// Обрізає або доповнює карту до нового розміру (якір — лівий верхній кут).
+    /// Нові клітинки — земля; посилання на сутності в тайлах не чіпаються.
+    pub fn resize(&mut self, width: i32, height: i32) {
+        let mut tiles = Vec::with_capacity((width * height).max(0) as usize);
+        for y in 0..height {
+            for x in 0..width {
+                tiles.push(self.get_tile((x, y)).copied().unwrap_or_else(Tile::walkable));
+            }
+        }
+        self.width = width;
+        self.height = height;
+        self.tiles = tiles;
     }
 
     /// Будує стіну, якщо це можливо.
@@ -1,6 +1,7 @@
 // src/map/mod.rs
 
 pub mod tile;
+#[allow(clippy::module_inception)]
 pub mod map;
 pub mod position;
 
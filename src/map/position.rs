@@ -26,7 +26,7 @@ impl MapPosition {
     pub fn right(&self, dist: i32) -> Self { self.offset(dist, 0) }
 
     pub fn manhattan_distance(&self, other: &Self) -> u32 {
-        (self.0.x - other.0.x).abs() as u32 + (self.0.y - other.0.y).abs() as u32
+        (self.0.x - other.0.x).unsigned_abs() + (self.0.y - other.0.y).unsigned_abs()
     }
     pub fn neighbors(&self) -> [Self; 4] {
         [
@@ -36,6 +36,19 @@ impl MapPosition {
             self.right(1),
         ]
     }
+
+    /// Усі клітинки в межах манхеттенської відстані `radius` (разом із самою).
+    pub fn diamond(&self, radius: u32) -> Vec<Self> {
+        let r = radius as i32;
+        let mut cells = Vec::new();
+        for dy in -r..=r {
+            let span = r - dy.abs();
+            for dx in -span..=span {
+                cells.push(self.offset(dx, dy));
+            }
+        }
+        cells
+    }
 }
 
 impl From<&MapPosition> for MapPosition {
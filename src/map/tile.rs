@@ -32,6 +32,7 @@ impl Tile {
     pub fn tile_type(&self) -> TileType { self.tile_type }
     pub fn symbol(&self) -> char { self.symbol }
     pub fn entity_id(&self) -> Option<u32> { self.entity_id }
+    pub fn powerup(&self) -> PowerupType { self.powerup }
 
     pub fn is_walkable(&self) -> bool { matches!(self.tile_type, TileType::WalkableGeneric) }
     pub fn is_solid(&self) -> bool { matches!(self.tile_type, TileType::Wall) }
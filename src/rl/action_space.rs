@@ -0,0 +1,99 @@
+// src/rl/action_space.rs
+
+use crate::state::modes::game::{GameEngine, EngineCommand, EngineError};
+use crate::specials::entity::EntityID;
+
+/// Фіксований дискретний простір дій для одного юніта.
+///
+/// Індекси: `0` — кінець ходу, далі `offsets().len()` ходів на зсув
+/// у ромбі радіуса `radius`, далі стільки ж атак по клітинці з тим самим зсувом.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActionSpace {
+    radius: i32,
+    offsets: Vec<(i32, i32)>,
+}
+
+impl Default for ActionSpace {
+    fn default() -> Self {
+        Self::new(5)
+    }
+}
+
+impl ActionSpace {
+    pub fn new(radius: u32) -> Self {
+        let r = radius as i32;
+        let mut offsets = Vec::new();
+        for dy in -r..=r {
+            let span = r - dy.abs();
+            for dx in -span..=span {
+                if (dx, dy) != (0, 0) {
+                    offsets.push((dx, dy));
+                }
+            }
+        }
+        ActionSpace { radius: r, offsets }
+    }
+
+    pub fn radius(&self) -> u32 { self.radius as u32 }
+
+    /// Зсуви (dx, dy) у порядку індексації.
+    pub fn offsets(&self) -> &[(i32, i32)] { &self.offsets }
+
+    /// Розмір простору дій (довжина маски).
+    pub fn size(&self) -> usize {
+        1 + self.offsets.len() * 2
+    }
+
+    /// Перетворює індекс на команду для юніта `entity`.
+    /// Помилка, якщо індекс поза простором, юніта немає або на клітинці атаки нікого немає.
+    pub fn decode(&self, engine: &GameEngine, entity: EntityID, index: usize) -> Result<EngineCommand, EngineError> {
+        if index >= self.size() {
+            return Err(EngineError::ActionIndexOutOfRange { index, size: self.size() });
+        }
+
+        if index == 0 {
+            return Ok(EngineCommand::EndTurn);
+        }
+
+        let origin = engine.world().get_entity(entity)
+            .ok_or(EngineError::EntityNotFound(entity))?
+            .position();
+        let n = self.offsets.len();
+        let slot = index - 1;
+
+        if slot < n {
+            let (dx, dy) = self.offsets[slot];
+            Ok(EngineCommand::Move { entity, to: origin.offset(dx, dy) })
+        } else {
+            let (dx, dy) = self.offsets[slot - n];
+            let pos = origin.offset(dx, dy);
+            let target = engine.world().get_entity_id_at(pos).ok_or(EngineError::NoTarget(pos))?;
+            Ok(EngineCommand::Attack { attacker: entity, target })
+        }
+    }
+
+    /// Маска легальних дій: `true` там, де рушій прийме дію.
+    /// Для мертвого, відсутнього чи не поточного юніта вся маска `false`.
+    pub fn mask(&self, engine: &GameEngine, entity: EntityID) -> Vec<bool> {
+        let mut mask = vec![false; self.size()];
+
+        let origin = match engine.world().get_entity(entity) {
+            Some(e) if !e.is_dead() && e.team() == engine.current_team() => e.position(),
+            _ => return mask,
+        };
+        // Кінець ходу доступний навіть застаненому юніту.
+        mask[0] = true;
+
+        let legal = engine.legal_actions(entity);
+        let n = self.offsets.len();
+
+        for (i, &(dx, dy)) in self.offsets.iter().enumerate() {
+            let pos = origin.offset(dx, dy);
+            mask[1 + i] = legal.can_move_to(pos);
+            mask[1 + n + i] = engine.world().get_entity_id_at(pos)
+                .is_some_and(|id| legal.can_attack(id));
+        }
+
+        mask
+    }
+}
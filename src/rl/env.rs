@@ -0,0 +1,340 @@
+// src/rl/env.rs
+
+use crate::state::WorldState;
+use crate::state::modes::game::{GameEngine, EngineCommand, EngineError, GameEvent, WinCondition};
+use crate::state::modes::game::ai::AiSystem;
+use crate::specials::entity::EntityID;
+use crate::specials::skill::SkillTable;
+use crate::map::position::MapPosition;
+
+use super::observation::{ObservationEncoder, ObservationTensor};
+use super::action_space::ActionSpace;
+
+/// Налаштування епізоду та ваги функції винагороди.
+#[derive(Debug, Clone)]
+pub struct EnvConfig {
+    /// Команда, якою керує агент. Решта команд ходять через `AiSystem`.
+    pub agent_team: u32,
+    /// Ліміт кроків агента на епізод (після нього епізод обрізається).
+    pub max_steps: u32,
+    /// Радіус випадкового зсуву юнітів при `reset` (0 = карта як є).
+    pub spawn_jitter: i32,
+    /// Умови перемоги для кожного епізоду; `None` — ті, що записані в карті.
+    pub win_conditions: Option<Vec<WinCondition>>,
+    /// Параметри вмінь (за замовчуванням — вбудований `skills.json`).
+    pub skills: SkillTable,
+    /// Кодувальник площин для `Observation::planes`.
+    pub encoder: ObservationEncoder,
+    /// Дискретний простір дій для `action_mask`/`step_discrete`.
+    pub action_space: ActionSpace,
+
+    pub damage_dealt_reward: f32,
+    pub damage_taken_penalty: f32,
+    pub kill_reward: f32,
+    pub death_penalty: f32,
+    pub invalid_action_penalty: f32,
+    pub win_reward: f32,
+    pub loss_penalty: f32,
+}
+
+impl Default for EnvConfig {
+    fn default() -> Self {
+        EnvConfig {
+            agent_team: 1,
+            max_steps: 500,
+            spawn_jitter: 0,
+            win_conditions: None,
+            skills: SkillTable::default(),
+            encoder: ObservationEncoder::default(),
+            action_space: ActionSpace::default(),
+
+            damage_dealt_reward: 0.1,
+            damage_taken_penalty: 0.1,
+            kill_reward: 1.0,
+            death_penalty: 1.0,
+            invalid_action_penalty: 0.05,
+            win_reward: 10.0,
+            loss_penalty: 10.0,
+        }
+    }
+}
+
+/// Знімок одного юніта для агента.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnitObservation {
+    pub id: EntityID,
+    pub team: u32,
+    pub position: MapPosition,
+    pub health: u32,
+    pub max_health: u32,
+    pub energy: u32,
+    pub max_energy: u32,
+    pub damage: u32,
+    pub attack_range: u32,
+}
+
+/// Те, що бачить агент після `reset`/`step`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Observation {
+    pub current_team: u32,
+    pub step: u32,
+    /// Лише живі юніти, у порядку зберігання в `WorldState`.
+    pub units: Vec<UnitObservation>,
+    /// Повнокарткові площини від `EnvConfig::encoder`.
+    pub planes: ObservationTensor,
+}
+
+/// Додаткова інформація про крок (аналог `info` у Gym).
+#[derive(Debug, Clone, Default)]
+pub struct StepInfo {
+    /// Події, спричинені командою агента (і ходами суперників після `EndTurn`).
+    pub events: Vec<GameEvent>,
+    /// Причина відмови рушія, якщо команда була нелегальною.
+    pub failure: Option<EngineError>,
+    /// Епізод завершився через `max_steps`, а не перемогою/поразкою.
+    pub truncated: bool,
+    /// Команда-переможець, якщо вона вже визначилась.
+    pub winner: Option<u32>,
+}
+
+/// Gym-подібне середовище поверх `GameEngine`.
+pub struct TelosEnv {
+    template: WorldState,
+    engine: GameEngine,
+    config: EnvConfig,
+    steps: u32,
+    done: bool,
+}
+
+impl TelosEnv {
+    pub fn new(template: WorldState, config: EnvConfig) -> Self {
+        let mut env = TelosEnv {
+            engine: GameEngine::new(template.clone()),
+            template,
+            config,
+            steps: 0,
+            done: false,
+        };
+        env.reset(0);
+        env
+    }
+
+    // =========================================================================
+    //                             GETTERS (API)
+    // =========================================================================
+
+    pub fn engine(&self) -> &GameEngine { &self.engine }
+    pub fn config(&self) -> &EnvConfig { &self.config }
+    pub fn is_done(&self) -> bool { self.done }
+
+    // =========================================================================
+    //                              GYM API
+    // =========================================================================
+
+    /// Починає новий епізод з копії шаблонного світу.
+    /// `seed` визначає лише випадковий зсув юнітів (`spawn_jitter`).
+    pub fn reset(&mut self, seed: u64) -> Observation {
+        let mut world = self.template.clone();
+        if self.config.spawn_jitter > 0 {
+            Self::jitter_spawns(&mut world, self.config.spawn_jitter, &mut SplitMix64(seed));
+        }
+
+        self.engine = GameEngine::new(world);
+        if let Some(conditions) = &self.config.win_conditions {
+            self.engine.set_win_conditions(conditions.clone());
+        }
+        self.engine.set_skill_table(self.config.skills.clone());
+        self.steps = 0;
+        self.done = false;
+
+        // Якщо першою ходить не команда агента — даємо суперникам доходити.
+        self.advance_to_agent_turn();
+        self.engine.drain_events();
+        self.done = self.outcome().is_some();
+
+        self.observe()
+    }
+
+    pub fn step(&mut self, cmd: EngineCommand) -> (Observation, f32, bool, StepInfo) {
+        let mut info = StepInfo::default();
+
+        if self.done {
+            info.winner = self.outcome().flatten();
+            return (self.observe(), 0.0, true, info);
+        }
+
+        let before = self.team_stats();
+        let mut reward = 0.0;
+
+        if let Err(e) = self.engine.apply(cmd) {
+            info.failure = Some(e);
+        }
+        if cmd == EngineCommand::EndTurn {
+            self.advance_to_agent_turn();
+        }
+        info.events = self.engine.drain_events();
+
+        if info.failure.is_some() {
+            reward -= self.config.invalid_action_penalty;
+        }
+
+        let after = self.team_stats();
+        let cfg = &self.config;
+        reward += before.enemy_hp.saturating_sub(after.enemy_hp) as f32 * cfg.damage_dealt_reward;
+        reward -= before.own_hp.saturating_sub(after.own_hp) as f32 * cfg.damage_taken_penalty;
+        reward += before.enemy_alive.saturating_sub(after.enemy_alive) as f32 * cfg.kill_reward;
+        reward -= before.own_alive.saturating_sub(after.own_alive) as f32 * cfg.death_penalty;
+
+        self.steps += 1;
+
+        if let Some(winner) = self.outcome() {
+            self.done = true;
+            info.winner = winner;
+            match winner {
+                Some(team) if team == self.config.agent_team => reward += self.config.win_reward,
+                _ => reward -= self.config.loss_penalty,
+            }
+        } else if self.steps >= self.config.max_steps {
+            self.done = true;
+            info.truncated = true;
+        }
+
+        (self.observe(), reward, self.done, info)
+    }
+
+    /// Маска легальних дискретних дій для юніта (див. `ActionSpace`).
+    pub fn action_mask(&self, entity: EntityID) -> Vec<bool> {
+        self.config.action_space.mask(&self.engine, entity)
+    }
+
+    /// `step` для дискретного індексу. Індекс, що не декодується в команду,
+    /// рахується як нелегальна дія.
+    pub fn step_discrete(&mut self, entity: EntityID, index: usize) -> (Observation, f32, bool, StepInfo) {
+        match self.config.action_space.decode(&self.engine, entity, index) {
+            Ok(cmd) => self.step(cmd),
+            Err(e) => {
+                let info = StepInfo {
+                    failure: Some(e),
+                    ..StepInfo::default()
+                };
+                (self.observe(), -self.config.invalid_action_penalty, self.done, info)
+            }
+        }
+    }
+
+    pub fn observe(&self) -> Observation {
+        let world = self.engine.world();
+        let units = world.entities.iter()
+            .filter(|e| !e.is_dead())
+            .map(|e| UnitObservation {
+                id: e.id(),
+                team: e.team(),
+                position: e.position(),
+                health: e.health(),
+                max_health: e.max_health(),
+                energy: e.energy(),
+                max_energy: e.max_energy(),
+                damage: e.damage(),
+                attack_range: e.attack_range(),
+            })
+            .collect();
+
+        Observation {
+            current_team: world.current_team_turn,
+            step: self.steps,
+            units,
+            planes: self.config.encoder.encode(world),
+        }
+    }
+
+    // =========================================================================
+    //                            PRIVATE HELPERS
+    // =========================================================================
+
+    /// Ходи суперників (через `AiSystem`), доки черга не повернеться до агента.
+    fn advance_to_agent_turn(&mut self) {
+        let agent_team = self.config.agent_team;
+        // Кожна жива команда отримує максимум один хід за коло.
+        let max_turns = self.engine.world().entities.len() + 1;
+
+        for _ in 0..max_turns {
+            if self.outcome().is_some() { break; }
+
+            let current = self.engine.current_team();
+            if current == agent_team { break; }
+
+            AiSystem::perform_turn(&mut self.engine, current);
+            let _ = self.engine.apply(EngineCommand::EndTurn);
+        }
+    }
+
+    /// `None` — епізод триває; `Some(winner)` — завершений (`winner = None`: нічия
+    /// або агента знищено, поки інші команди ще воюють).
+    fn outcome(&self) -> Option<Option<u32>> {
+        if let Some(outcome) = self.engine.outcome() {
+            return Some(outcome.winner);
+        }
+        if self.team_stats().own_alive == 0 {
+            return Some(None);
+        }
+        None
+    }
+
+    fn team_stats(&self) -> TeamStats {
+        let agent_team = self.config.agent_team;
+        let mut stats = TeamStats::default();
+
+        for e in self.engine.world().entities.iter().filter(|e| !e.is_dead()) {
+            if e.team() == agent_team {
+                stats.own_hp += e.health();
+                stats.own_alive += 1;
+            } else {
+                stats.enemy_hp += e.health();
+                stats.enemy_alive += 1;
+            }
+        }
+        stats
+    }
+
+    fn jitter_spawns(world: &mut WorldState, radius: i32, rng: &mut SplitMix64) {
+        let ids: Vec<EntityID> = world.entities.iter().map(|e| e.id()).collect();
+        let span = (radius * 2 + 1) as u64;
+
+        for id in ids {
+            let origin = match world.get_entity(id) {
+                Some(e) => e.position(),
+                None => continue,
+            };
+
+            // Кілька спроб; якщо все зайнято — юніт лишається на місці.
+            for _ in 0..8 {
+                let dx = (rng.next_u64() % span) as i32 - radius;
+                let dy = (rng.next_u64() % span) as i32 - radius;
+                if world.relocate_entity(id, origin.offset(dx, dy)) {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct TeamStats {
+    own_hp: u32,
+    own_alive: u32,
+    enemy_hp: u32,
+    enemy_alive: u32,
+}
+
+/// Мінімальний детермінований ГПВЧ, щоб не тягнути `rand` заради `reset`.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
@@ -0,0 +1,10 @@
+// src/rl/mod.rs
+// Headless-шар для навчання агентів: жодних залежностей від ratatui/crossterm.
+
+pub mod env;
+pub mod observation;
+pub mod action_space;
+
+pub use env::{TelosEnv, EnvConfig, Observation, UnitObservation, StepInfo};
+pub use observation::{ObservationEncoder, ObservationShape, ObservationTensor};
+pub use action_space::ActionSpace;
@@ -0,0 +1,187 @@
+// src/rl/observation.rs
+
+use crate::state::WorldState;
+use crate::specials::entity::EntityID;
+use crate::specials::powerup::PowerupType;
+use crate::map::position::MapPosition;
+
+// Фіксовані канали (командні канали йдуть одразу після них).
+const CH_WALL: usize = 0;
+const CH_WALKABLE: usize = 1;
+const CH_HEALTH: usize = 2;
+const CH_ENERGY: usize = 3;
+const CH_POWERUP_HEAL: usize = 4;
+const CH_POWERUP_SPEED: usize = 5;
+const CH_POWERUP_WALLBREAK: usize = 6;
+const CH_CURRENT_TEAM: usize = 7;
+const FIXED_CHANNELS: usize = 8;
+
+/// Форма тензора у порядку CHW (канали, висота, ширина).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ObservationShape {
+    pub channels: usize,
+    pub height: usize,
+    pub width: usize,
+}
+
+impl ObservationShape {
+    /// Загальна кількість елементів (довжина плаского вектора).
+    pub fn len(&self) -> usize {
+        self.channels * self.height * self.width
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn as_array(&self) -> [usize; 3] {
+        [self.channels, self.height, self.width]
+    }
+}
+
+/// Закодоване спостереження: плаский буфер + його форма.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObservationTensor {
+    data: Vec<f32>,
+    shape: ObservationShape,
+}
+
+impl ObservationTensor {
+    fn zeros(shape: ObservationShape) -> Self {
+        ObservationTensor { data: vec![0.0; shape.len()], shape }
+    }
+
+    pub fn shape(&self) -> ObservationShape { self.shape }
+    pub fn as_slice(&self) -> &[f32] { &self.data }
+    pub fn into_vec(self) -> Vec<f32> { self.data }
+
+    /// Значення в каналі `c` для клітинки (x, y) тензора.
+    pub fn get(&self, c: usize, x: usize, y: usize) -> f32 {
+        self.data[self.index(c, x, y)]
+    }
+
+    fn set(&mut self, c: usize, x: usize, y: usize, value: f32) {
+        let idx = self.index(c, x, y);
+        self.data[idx] = value;
+    }
+
+    fn index(&self, c: usize, x: usize, y: usize) -> usize {
+        (c * self.shape.height + y) * self.shape.width + x
+    }
+}
+
+/// Детермінований кодувальник `WorldState` у багатоканальні площини.
+///
+/// Канали: стіни, прохідні клітинки, здоров'я та енергія (нормовані до 0..1),
+/// три канали паверапів, юніти команди, що зараз ходить, і по одному каналу
+/// зайнятості на кожну команду `0..max_teams`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ObservationEncoder {
+    max_teams: u32,
+}
+
+impl Default for ObservationEncoder {
+    fn default() -> Self {
+        Self::new(4)
+    }
+}
+
+impl ObservationEncoder {
+    /// `max_teams` — кількість командних каналів; юніти команд поза
+    /// цим діапазоном потрапляють лише в загальні канали.
+    pub fn new(max_teams: u32) -> Self {
+        ObservationEncoder { max_teams }
+    }
+
+    pub fn channels(&self) -> usize {
+        FIXED_CHANNELS + self.max_teams as usize
+    }
+
+    /// Форма повнокарткового спостереження для даного світу.
+    pub fn shape(&self, world: &WorldState) -> ObservationShape {
+        ObservationShape {
+            channels: self.channels(),
+            height: world.map.height().max(0) as usize,
+            width: world.map.width().max(0) as usize,
+        }
+    }
+
+    /// Форма егоцентричного вікна радіуса `radius` (сторона `2 * radius + 1`).
+    pub fn crop_shape(&self, radius: u32) -> ObservationShape {
+        let side = radius as usize * 2 + 1;
+        ObservationShape { channels: self.channels(), height: side, width: side }
+    }
+
+    /// Кодує всю карту.
+    pub fn encode(&self, world: &WorldState) -> ObservationTensor {
+        let shape = self.shape(world);
+        self.encode_window(world, MapPosition::new(0, 0), shape)
+    }
+
+    /// Кодує квадратне вікно з центром на сутності. Клітинки за межами
+    /// карти подаються як стіни. `None`, якщо сутності немає.
+    pub fn encode_egocentric(&self, world: &WorldState, center: EntityID, radius: u32) -> Option<ObservationTensor> {
+        let pos = world.get_entity(center)?.position();
+        let r = radius as i32;
+        Some(self.encode_window(world, pos.offset(-r, -r), self.crop_shape(radius)))
+    }
+
+    // =========================================================================
+    //                            PRIVATE HELPERS
+    // =========================================================================
+
+    /// Заповнює тензор `shape`, де клітинка (0, 0) відповідає `origin` на карті.
+    fn encode_window(&self, world: &WorldState, origin: MapPosition, shape: ObservationShape) -> ObservationTensor {
+        let mut tensor = ObservationTensor::zeros(shape);
+        let map = &world.map;
+
+        for y in 0..shape.height {
+            for x in 0..shape.width {
+                let pos = origin.offset(x as i32, y as i32);
+                let tile = match map.get_tile(pos) {
+                    Some(t) => t,
+                    None => {
+                        tensor.set(CH_WALL, x, y, 1.0);
+                        continue;
+                    }
+                };
+
+                if tile.is_solid() { tensor.set(CH_WALL, x, y, 1.0); }
+                if tile.is_walkable() { tensor.set(CH_WALKABLE, x, y, 1.0); }
+
+                let powerup_channel = match tile.powerup() {
+                    PowerupType::HealingPotion => Some(CH_POWERUP_HEAL),
+                    PowerupType::SpeedBoost => Some(CH_POWERUP_SPEED),
+                    PowerupType::WallBreaker => Some(CH_POWERUP_WALLBREAK),
+                    PowerupType::None => None,
+                };
+                if let Some(c) = powerup_channel {
+                    tensor.set(c, x, y, 1.0);
+                }
+            }
+        }
+
+        for e in world.entities.iter().filter(|e| !e.is_dead()) {
+            let rel = e.position() - origin;
+            if rel.x() < 0 || rel.y() < 0 { continue; }
+            let (x, y) = (rel.x() as usize, rel.y() as usize);
+            if x >= shape.width || y >= shape.height { continue; }
+
+            tensor.set(CH_HEALTH, x, y, ratio(e.health(), e.max_health()));
+            tensor.set(CH_ENERGY, x, y, ratio(e.energy(), e.max_energy()));
+
+            if e.team() == world.current_team_turn {
+                tensor.set(CH_CURRENT_TEAM, x, y, 1.0);
+            }
+            if e.team() < self.max_teams {
+                tensor.set(FIXED_CHANNELS + e.team() as usize, x, y, 1.0);
+            }
+        }
+
+        tensor
+    }
+}
+
+fn ratio(value: u32, max: u32) -> f32 {
+    if max == 0 { 0.0 } else { (value as f32 / max as f32).min(1.0) }
+}
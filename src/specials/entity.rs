@@ -1,6 +1,8 @@
 // src/specials/entity.rs
 
 use crate::map::position::MapPosition; 
+use crate::specials::skill::{self, Skill};
+use crate::specials::status::{StatusEffect, StatusKind};
 use serde::{Serialize, Deserialize};
 
 pub type EntityID = u32;
@@ -27,9 +29,16 @@ pub struct Entity {
 
     // State
     position: MapPosition,
-    stunned_for_turns: u32,
+    #[serde(default)]
+    statuses: Vec<StatusEffect>,
     is_selected: bool,
     is_ai: bool,
+
+    // Inventory
+    #[serde(default)]
+    wall_breaker_charges: u32,
+    #[serde(default = "skill::default_loadout")]
+    skills: Vec<Skill>,
 }
 
 impl Entity {
@@ -37,6 +46,7 @@ impl Entity {
     //                            CONSTRUCTOR
     // =========================================================================
     
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         id: EntityID,
         symbol: char,
@@ -62,9 +72,11 @@ impl Entity {
             attack_range,
 
             position,
-            stunned_for_turns: 0,
+            statuses: Vec::new(),
             is_selected: false,
             is_ai: false, 
+            wall_breaker_charges: 0,
+            skills: skill::default_loadout(),
         }
     }
 
@@ -86,14 +98,27 @@ impl Entity {
     pub fn max_energy(&self) -> u32 { self.max_energy }
     pub fn damage(&self) -> u32 { self.damage }
     pub fn attack_range(&self) -> u32 { self.attack_range }
+    pub fn wall_breaker_charges(&self) -> u32 { self.wall_breaker_charges }
+    pub fn skills(&self) -> &[Skill] { &self.skills }
 
     // Flags
     pub fn is_selected(&self) -> bool { self.is_selected }
     pub fn is_ai(&self) -> bool { self.is_ai }
-    pub fn is_active(&self) -> bool { self.stunned_for_turns == 0 }
-    pub fn is_stunned(&self) -> bool { self.stunned_for_turns > 0 }
+    pub fn is_active(&self) -> bool { !self.is_stunned() }
+    pub fn is_stunned(&self) -> bool { self.has_status(StatusKind::Stun) }
+    pub fn is_rooted(&self) -> bool { self.has_status(StatusKind::Rooted) }
+    pub fn stunned_for_turns(&self) -> u32 {
+        self.status(StatusKind::Stun).map_or(0, |s| s.turns_left())
+    }
     pub fn is_dead(&self) -> bool { self.health == 0 }
 
+    // Status effects
+    pub fn statuses(&self) -> &[StatusEffect] { &self.statuses }
+    pub fn status(&self, kind: StatusKind) -> Option<&StatusEffect> {
+        self.statuses.iter().find(|s| s.kind() == kind)
+    }
+    pub fn has_status(&self, kind: StatusKind) -> bool { self.status(kind).is_some() }
+
     /// Перевіряє, чи вистачає енергії і чи юніт не застанений.
     pub fn can_act(&self, cost: u32) -> bool {
         self.is_active() && self.energy >= cost
@@ -119,14 +144,59 @@ impl Entity {
         self.is_ai = is_ai;
     }
 
+    pub fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+
+    pub fn set_symbol(&mut self, symbol: char) {
+        self.symbol = symbol;
+    }
+
+    /// Для редактора: новий максимум HP, поточне здоров'я заповнюється до нього.
+    pub fn set_max_health(&mut self, hp: u32) {
+        self.max_health = hp;
+        self.health = hp;
+    }
+
+    /// Для редактора: новий максимум енергії, поточна енергія заповнюється до нього.
+    pub fn set_max_energy(&mut self, energy: u32) {
+        self.max_energy = energy;
+        self.energy = energy;
+    }
+
+    pub fn set_damage(&mut self, damage: u32) {
+        self.damage = damage;
+    }
+
+    pub fn set_attack_range(&mut self, range: u32) {
+        self.attack_range = range;
+    }
+
     // =========================================================================
     //                            GAMEPLAY LOGIC
     // =========================================================================
 
-    pub fn reduce_stun(&mut self) {
-        if self.stunned_for_turns > 0 {
-            self.stunned_for_turns -= 1;
+    /// Накладає ефект; ефект того ж виду поєднується за його правилом стакання.
+    pub fn apply_status(&mut self, effect: StatusEffect) {
+        if effect.is_expired() { return; }
+        match self.statuses.iter_mut().find(|s| s.kind() == effect.kind()) {
+            Some(existing) => existing.merge(effect),
+            None => self.statuses.push(effect),
+        }
+    }
+
+    pub fn stun(&mut self, turns: u32) {
+        self.apply_status(StatusEffect::new(StatusKind::Stun, turns, 0));
+    }
+
+    /// Кінець ходу власної команди: зменшує тривалості. Повертає ефекти, що спали.
+    pub fn tick_statuses(&mut self) -> Vec<StatusKind> {
+        for s in self.statuses.iter_mut() {
+            s.tick();
         }
+        let expired = self.statuses.iter().filter(|s| s.is_expired()).map(|s| s.kind()).collect();
+        self.statuses.retain(|s| !s.is_expired());
+        expired
     }
     
     /// Спроба виконати дію. Повертає true, якщо енергія була витрачена.
@@ -139,15 +209,23 @@ impl Entity {
         }
     }
     
-    /// Відновлює енергію до максимуму (якщо не застанений).
+    /// Відновлює енергію до максимуму (якщо не застанений); Haste додає понад максимум.
     pub fn refill_energy(&mut self) {
         if self.is_active() {
-            self.energy = self.max_energy; 
+            let haste = self.status(StatusKind::Haste).map_or(0, |s| s.potency());
+            self.energy = self.max_energy + haste; 
         }
     }
     
-    /// Наносить шкоду. Повертає true, якщо сутність померла.
+    /// Наносить шкоду (спершу її поглинає Shield). Повертає true, якщо сутність померла.
     pub fn take_damage(&mut self, amount: u32) -> bool {
+        let mut amount = amount;
+        if let Some(shield) = self.statuses.iter_mut().find(|s| s.kind() == StatusKind::Shield) {
+            amount = shield.absorb(amount);
+            if shield.potency() == 0 {
+                self.statuses.retain(|s| s.kind() != StatusKind::Shield);
+            }
+        }
         self.health = self.health.saturating_sub(amount);
         self.is_dead()
     }
@@ -157,4 +235,33 @@ impl Entity {
         if self.is_dead() { return; }
         self.health = self.health.saturating_add(amount).min(self.max_health);
     }
+
+    /// Тимчасова енергія понад максимум (зникне при наступному `refill_energy`).
+    pub fn boost_energy(&mut self, amount: u32) {
+        self.energy = self.energy.saturating_add(amount);
+    }
+
+    /// Запускає перезарядку вміння в слоті `slot` на `turns` ходів.
+    pub fn start_skill_cooldown(&mut self, slot: usize, turns: u32) {
+        if let Some(s) = self.skills.get_mut(slot) {
+            s.start_cooldown(turns);
+        }
+    }
+
+    pub fn tick_cooldowns(&mut self) {
+        for s in self.skills.iter_mut() {
+            s.tick();
+        }
+    }
+
+    pub fn add_wall_breaker(&mut self) {
+        self.wall_breaker_charges += 1;
+    }
+
+    /// Витрачає один заряд. Повертає false, якщо зарядів немає.
+    pub fn use_wall_breaker(&mut self) -> bool {
+        if self.wall_breaker_charges == 0 { return false; }
+        self.wall_breaker_charges -= 1;
+        true
+    }
 }
\ No newline at end of file
@@ -2,6 +2,10 @@
 
 pub mod entity; 
 pub mod powerup;
+pub mod skill;
+pub mod status;
 
 pub use entity::Entity;
 pub use powerup::PowerupType;
+pub use skill::{Skill, SkillKind};
+pub use status::{StatusEffect, StatusKind};
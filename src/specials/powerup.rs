@@ -1,5 +1,9 @@
 // src/specials/powerup.rs
 use serde::{Serialize,Deserialize};
+
+// --- EFFECT CONSTANTS ---
+pub const HEAL_AMOUNT: u32 = 20;
+pub const SPEED_BOOST_ENERGY: u32 = 5;
 // Це те, на що натякав наш PowerupTypeID! 
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PowerupType {
@@ -20,11 +24,37 @@ impl PowerupType {
         }
     }
     
-    pub fn is_some(&self) -> bool{
+    /// Символ паверапа на карті.
+    pub fn symbol(&self) -> char {
         match self {
-            PowerupType::None => false,
-            _ => true,
+            PowerupType::None => ' ',
+            PowerupType::HealingPotion => '+',
+            PowerupType::SpeedBoost => '»',
+            PowerupType::WallBreaker => '¤',
         }
+    }
+
+    /// Людська назва (для меню редактора).
+    pub fn name(&self) -> &'static str {
+        match self {
+            PowerupType::None => "None",
+            PowerupType::HealingPotion => "Healing",
+            PowerupType::SpeedBoost => "Speed",
+            PowerupType::WallBreaker => "Wall Breaker",
+        }
+    }
+
+    /// Наступний реальний тип по колу (None пропускається).
+    pub fn next(&self) -> PowerupType {
+        match self {
+            PowerupType::None | PowerupType::WallBreaker => PowerupType::HealingPotion,
+            PowerupType::HealingPotion => PowerupType::SpeedBoost,
+            PowerupType::SpeedBoost => PowerupType::WallBreaker,
+        }
+    }
+
+    pub fn is_some(&self) -> bool{
+        !matches!(self, PowerupType::None)
     } 
     
     pub fn take(&mut self)-> PowerupType {
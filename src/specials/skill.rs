@@ -0,0 +1,138 @@
+// src/specials/skill.rs
+
+use serde::{Serialize, Deserialize};
+use crate::specials::status::StatusEffect;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SkillKind {
+    StunStrike,
+    AreaBlast,
+    HealAlly,
+    Dash,
+}
+
+/// На що можна націлити вміння.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TargetShape {
+    /// Живий ворожий юніт.
+    Enemy,
+    /// Живий союзник (або сам юніт).
+    Ally,
+    /// Будь-яка клітинка карти; зачіпає всіх у ромбі `radius`, крім самого юніта.
+    Area { radius: u32 },
+    /// Вільна прохідна клітинка.
+    EmptyTile,
+}
+
+/// Що вміння робить з цілями.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkillEffect {
+    /// `power` шкоди кожній цілі.
+    Damage,
+    /// `power` лікування кожній цілі.
+    Heal,
+    /// Переміщення на ціль шляхом не довшим за `range` (як звичайний хід).
+    Dash,
+}
+
+/// Параметри вміння — один запис таблиці `SkillTable`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SkillSpec {
+    pub kind: SkillKind,
+    pub name: String,
+    pub energy_cost: u32,
+    pub range: u32,
+    pub cooldown: u32,
+    pub shape: TargetShape,
+    pub effect: SkillEffect,
+    #[serde(default)]
+    pub power: u32,
+    /// Ефект, який отримує кожен уцілілий юніт-ціль.
+    #[serde(default)]
+    pub applies: Option<StatusEffect>,
+}
+
+impl SkillKind {
+    pub const ALL: [SkillKind; 4] = [
+        SkillKind::StunStrike,
+        SkillKind::AreaBlast,
+        SkillKind::HealAlly,
+        SkillKind::Dash,
+    ];
+}
+
+/// Вбудована таблиця вмінь.
+const BUILTIN_SKILLS: &str = include_str!("skills.json");
+
+/// Таблиця параметрів усіх вмінь. Рушій і AI читають вміння лише з неї,
+/// тож баланс змінюється JSON-файлом, а не кодом.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct SkillTable {
+    specs: Vec<SkillSpec>,
+}
+
+impl Default for SkillTable {
+    fn default() -> Self {
+        Self::from_json(BUILTIN_SKILLS).expect("built-in skills.json is valid")
+    }
+}
+
+impl SkillTable {
+    /// Розбирає таблицю; кожен `SkillKind` має бути описаний рівно один раз.
+    pub fn from_json(text: &str) -> serde_json::Result<Self> {
+        let specs: Vec<SkillSpec> = serde_json::from_str(text)?;
+        for kind in SkillKind::ALL {
+            let count = specs.iter().filter(|s| s.kind == kind).count();
+            if count != 1 {
+                let msg = format!("skill {:?} must be described once, found {}", kind, count);
+                return Err(serde::de::Error::custom(msg));
+            }
+        }
+        Ok(SkillTable { specs })
+    }
+
+    pub fn load(filename: &str) -> std::io::Result<Self> {
+        let text = std::fs::read_to_string(filename)?;
+        Ok(Self::from_json(&text)?)
+    }
+
+    pub fn specs(&self) -> &[SkillSpec] { &self.specs }
+
+    pub fn spec(&self, kind: SkillKind) -> &SkillSpec {
+        // `from_json` гарантує, що запис є для кожного виду
+        self.specs.iter().find(|s| s.kind == kind).expect("skill table covers every SkillKind")
+    }
+}
+
+/// Вміння в руках конкретного юніта (вид + поточна перезарядка).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Skill {
+    kind: SkillKind,
+    #[serde(default)]
+    cooldown_left: u32,
+}
+
+impl Skill {
+    pub fn new(kind: SkillKind) -> Self {
+        Skill { kind, cooldown_left: 0 }
+    }
+
+    pub fn kind(&self) -> SkillKind { self.kind }
+    pub fn cooldown_left(&self) -> u32 { self.cooldown_left }
+    pub fn is_ready(&self) -> bool { self.cooldown_left == 0 }
+
+    pub fn start_cooldown(&mut self, turns: u32) {
+        self.cooldown_left = turns;
+    }
+
+    /// Викликається на початку кожного ходу команди власника.
+    pub fn tick(&mut self) {
+        self.cooldown_left = self.cooldown_left.saturating_sub(1);
+    }
+}
+
+/// Набір вмінь за замовчуванням (нові юніти та старі збереження без `skills`).
+pub fn default_loadout() -> Vec<Skill> {
+    SkillKind::ALL.iter().map(|&k| Skill::new(k)).collect()
+}
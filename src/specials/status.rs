@@ -0,0 +1,88 @@
+// src/specials/status.rs
+
+use serde::{Serialize, Deserialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatusKind {
+    /// Юніт не діє і не відновлює енергію.
+    Stun,
+    /// `potency` шкоди на початку кожного свого ходу.
+    Poison,
+    /// `potency` лікування на початку кожного свого ходу.
+    Regeneration,
+    /// +`potency` енергії понад максимум при відновленні.
+    Haste,
+    /// Поглинає до `potency` шкоди, потім зникає.
+    Shield,
+    /// Юніт не може пересуватись (атаки й вміння дозволені).
+    Rooted,
+}
+
+/// Як новий ефект поєднується з уже наявним того ж виду.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stacking {
+    /// Тривалість і сила — максимум зі старого й нового.
+    Refresh,
+    /// Сила додається, тривалість — максимум.
+    Intensify,
+}
+
+impl StatusKind {
+    pub fn stacking(&self) -> Stacking {
+        match self {
+            StatusKind::Poison | StatusKind::Shield => Stacking::Intensify,
+            _ => Stacking::Refresh,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            StatusKind::Stun => "Stun",
+            StatusKind::Poison => "Poison",
+            StatusKind::Regeneration => "Regen",
+            StatusKind::Haste => "Haste",
+            StatusKind::Shield => "Shield",
+            StatusKind::Rooted => "Rooted",
+        }
+    }
+}
+
+/// Активний ефект на юніті. Тривалість спадає наприкінці ходу його команди.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatusEffect {
+    kind: StatusKind,
+    turns_left: u32,
+    #[serde(default)]
+    potency: u32,
+}
+
+impl StatusEffect {
+    pub fn new(kind: StatusKind, turns: u32, potency: u32) -> Self {
+        StatusEffect { kind, turns_left: turns, potency }
+    }
+
+    pub fn kind(&self) -> StatusKind { self.kind }
+    pub fn turns_left(&self) -> u32 { self.turns_left }
+    pub fn potency(&self) -> u32 { self.potency }
+    pub fn is_expired(&self) -> bool { self.turns_left == 0 }
+
+    /// Поєднує з ефектом того ж виду за правилом `StatusKind::stacking`.
+    pub fn merge(&mut self, other: StatusEffect) {
+        self.turns_left = self.turns_left.max(other.turns_left);
+        self.potency = match self.kind.stacking() {
+            Stacking::Refresh => self.potency.max(other.potency),
+            Stacking::Intensify => self.potency.saturating_add(other.potency),
+        };
+    }
+
+    /// Поглинає шкоду за рахунок сили ефекту. Повертає шкоду, що пройшла.
+    pub fn absorb(&mut self, amount: u32) -> u32 {
+        let absorbed = amount.min(self.potency);
+        self.potency -= absorbed;
+        amount - absorbed
+    }
+
+    pub fn tick(&mut self) {
+        self.turns_left = self.turns_left.saturating_sub(1);
+    }
+}
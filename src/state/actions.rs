@@ -6,7 +6,8 @@ use crate::map::position::MapPosition;
 pub enum MenuSelection {
     EnterBuildMode,
     EnterPlayMode,
-    LoadLatest,
+    LoadMap,
+    EditExisting,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -14,6 +15,54 @@ pub enum BuildTool {
     Wall,
     Floor,
     Unit,
+    Powerup,
+    Select,
+}
+
+/// Форма пензля для інструментів Wall / Floor / Powerup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrushShape {
+    Freehand,
+    Line,
+    Rect,
+    HollowRect,
+    Fill,
+}
+
+impl BrushShape {
+    pub fn next(&self) -> BrushShape {
+        match self {
+            BrushShape::Freehand => BrushShape::Line,
+            BrushShape::Line => BrushShape::Rect,
+            BrushShape::Rect => BrushShape::HollowRect,
+            BrushShape::HollowRect => BrushShape::Fill,
+            BrushShape::Fill => BrushShape::Freehand,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BrushShape::Freehand => "Freehand",
+            BrushShape::Line => "Line",
+            BrushShape::Rect => "Rect",
+            BrushShape::HollowRect => "Hollow Rect",
+            BrushShape::Fill => "Fill",
+        }
+    }
+}
+
+/// Клавіші, які отримує відкрита форма вводу (`state::form::Form`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormKey {
+    Char(char),
+    Backspace,
+    Delete,
+    Left,
+    Right,
+    Home,
+    End,
+    NextField,
+    PrevField,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -22,6 +71,7 @@ pub enum GameTool {
     Move,
     Attack,
     Skill,
+    Powerup,
 }
 
 #[derive(Debug, Clone)]
@@ -29,22 +79,43 @@ pub enum Action {
     QuitApp,
     BackToMenu,
     MenuSelect(MenuSelection),
+
+    // Camera (Editor & Game)
+    ScrollCamera { dx: i32, dy: i32 },
+    ResizeViewport { width: i32, height: i32 },
+
+    // Keyboard cursor (Editor & Game)
+    MoveCursor { dx: i32, dy: i32 },
+    CursorActivate,
     
+    // File Picker
+    PickerMove { delta: i32 },
+    /// Клік мишкою: вибрати файл із таким індексом і одразу відкрити.
+    PickerSelect { index: usize },
+    PickerConfirm,
+
     // Editor Actions
     CycleBuildTool,
     SaveMap,
     EditorClick { pos: MapPosition },
     EditorMenuClick { screen_x: i32, screen_y: i32 },
+    /// Мишка: натиснули, тягнемо, відпустили (малювання пензлем).
+    EditorStrokeStart { pos: MapPosition },
+    EditorStrokeMove { pos: MapPosition },
+    EditorStrokeEnd { pos: MapPosition },
     
     // Editor Input & Hotkeys
-    EditorType(char),     
+    EditorFormKey(FormKey),
     EditorKeyPress(char),  
-    EditorBackspace,
     EditorConfirm,
     EditorCancel,
+    EditorUndo,
+    EditorRedo,
 
     // Game Actions
     GameClick { pos: MapPosition },
     GameMenuClick { screen_x: i32, screen_y: i32 },
     GameKeyPress(char), 
+    /// Прокрутка бойового журналу (додатне — до старіших записів).
+    ScrollLog { delta: i32 },
 }
\ No newline at end of file
@@ -1,23 +1,35 @@
 // src/state/application_state.rs
 
 use super::actions::{Action, MenuSelection};
-use super::modes::{EditorMode, GameMode}; 
+use super::modes::{EditorMode, GameMode, FilePicker, PickerPurpose}; 
 use super::world_state::WorldState; 
 
+/// Стандартна карта для "New Game".
+const STANDARD_MAP_PATH: &str = "standart.json";
+
 pub enum AppState {
     Menu,
     Editor(EditorMode),
     Game(GameMode),
+    FilePicker(FilePicker),
     Exiting,
 }
 
 pub struct ApplicationState {
     pub state: AppState,
+    /// Повідомлення в головному меню (наприклад, чому не вдалося завантажити карту).
+    pub message: Option<String>,
+}
+
+impl Default for ApplicationState {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl ApplicationState {
     pub fn new() -> Self {
-        ApplicationState { state: AppState::Menu }
+        ApplicationState { state: AppState::Menu, message: None }
     }
 
     pub fn apply_action(&mut self, action: Action) {
@@ -30,32 +42,48 @@ impl ApplicationState {
                     match sel {
                         MenuSelection::EnterBuildMode => next_state = Some(AppState::Editor(EditorMode::new())),
                         MenuSelection::EnterPlayMode => {
-                            match WorldState::load("standart.json") {
+                            match WorldState::load(STANDARD_MAP_PATH) {
                                 Ok(ws) => next_state = Some(AppState::Game(GameMode::new(ws))),
-                                Err(_) => {}
-                            }
-                        },
-                        MenuSelection::LoadLatest => {
-                            match WorldState::load("map.json") {
-                                Ok(ws) => next_state = Some(AppState::Game(GameMode::new(ws))),
-                                Err(_) => {}
+                                Err(e) => self.message = Some(format!("Can't load '{}': {}", STANDARD_MAP_PATH, e)),
                             }
                         },
+                        MenuSelection::LoadMap => next_state = Some(AppState::FilePicker(FilePicker::new(PickerPurpose::Play))),
+                        MenuSelection::EditExisting => next_state = Some(AppState::FilePicker(FilePicker::new(PickerPurpose::Edit))),
                     }
                 } else if let Action::QuitApp = action {
                     next_state = Some(AppState::Exiting);
                 }
             },
             
+            // --- FILE PICKER ---
+            AppState::FilePicker(picker) => {
+                match action {
+                    Action::QuitApp => next_state = Some(AppState::Exiting),
+                    Action::BackToMenu => next_state = Some(AppState::Menu),
+                    Action::PickerMove { delta } => picker.move_selection(delta),
+                    Action::PickerSelect { index } if picker.select(index) => next_state = open_picked(picker),
+                    Action::PickerConfirm => next_state = open_picked(picker),
+                    _ => {}
+                }
+            },
+
             // --- EDITOR MODE ---
             AppState::Editor(editor) => {
                 match action {
                     Action::QuitApp => next_state = Some(AppState::Exiting),
                     Action::BackToMenu => next_state = Some(AppState::Menu),
                     
+                    Action::ScrollCamera { dx, dy } => editor.scroll_camera(dx, dy),
+                    Action::ResizeViewport { width, height } => editor.set_viewport(width, height),
+                    Action::MoveCursor { dx, dy } => editor.move_cursor(dx, dy),
+                    Action::CursorActivate => editor.handle_click(editor.cursor()),
+
                     Action::CycleBuildTool => editor.cycle_tool(),
                     Action::SaveMap => editor.save_map(), 
                     Action::EditorClick { pos } => editor.handle_click(pos),
+                    Action::EditorStrokeStart { pos } => editor.stroke_start(pos),
+                    Action::EditorStrokeMove { pos } => editor.stroke_move(pos),
+                    Action::EditorStrokeEnd { pos } => editor.stroke_end(pos),
                     
                     Action::EditorMenuClick { screen_x, screen_y } => {
                         if let Some(reaction) = editor.handle_menu_click(screen_x, screen_y) {
@@ -65,10 +93,11 @@ impl ApplicationState {
                         }
                     },
                     
-                    Action::EditorType(c) => editor.handle_typing(c),
+                    Action::EditorFormKey(key) => editor.handle_form_key(key),
                     Action::EditorConfirm => editor.confirm_input(),
-                    Action::EditorBackspace => editor.handle_backspace(),
                     Action::EditorCancel => editor.cancel_input(),
+                    Action::EditorUndo => editor.undo(),
+                    Action::EditorRedo => editor.redo(),
 
                     Action::EditorKeyPress(key) => {
                         if let Some(reaction) = editor.handle_keypress(key) {
@@ -88,6 +117,12 @@ impl ApplicationState {
                     Action::QuitApp => next_state = Some(AppState::Exiting),
                     Action::BackToMenu => next_state = Some(AppState::Menu),
                     
+                    Action::ScrollCamera { dx, dy } => game.scroll_camera(dx, dy),
+                    Action::ResizeViewport { width, height } => game.set_viewport(width, height),
+                    Action::MoveCursor { dx, dy } => game.move_cursor(dx, dy),
+                    Action::CursorActivate => game.handle_click(game.cursor()),
+
+                    Action::ScrollLog { delta } => game.scroll_log(delta),
                     Action::GameClick { pos } => game.handle_click(pos),
                     
                     Action::GameMenuClick { screen_x, screen_y } => { 
@@ -113,7 +148,25 @@ impl ApplicationState {
         }
 
         if let Some(ns) = next_state {
+            if !matches!(ns, AppState::Menu) {
+                self.message = None;
+            }
             self.state = ns;
         }
     }
-}
\ No newline at end of file
+}
+
+/// Завантажує вибраний у пікері файл; при помилці лишається в пікері з повідомленням.
+fn open_picked(picker: &mut FilePicker) -> Option<AppState> {
+    let path = picker.selected_path()?;
+    match WorldState::load(&path) {
+        Ok(ws) => Some(match picker.purpose() {
+            PickerPurpose::Play => AppState::Game(GameMode::new(ws)),
+            PickerPurpose::Edit => AppState::Editor(EditorMode::from_world(ws, &path)),
+        }),
+        Err(e) => {
+            picker.set_error(format!("Can't load '{}': {}", path, e));
+            None
+        }
+    }
+}
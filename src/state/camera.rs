@@ -0,0 +1,75 @@
+// src/state/camera.rs
+
+use crate::map::map::Map;
+use crate::map::position::MapPosition;
+
+/// На скільки клітинок зсувається камера за одне натискання стрілки.
+pub const SCROLL_STEP: i32 = 2;
+
+/// Вюпорт карти: яка світова клітинка в лівому верхньому куті і скільки клітинок видно.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Camera {
+    x: i32,
+    y: i32,
+    view_width: i32,
+    view_height: i32,
+}
+
+impl Camera {
+    pub fn offset(&self) -> MapPosition {
+        MapPosition::new(self.x, self.y)
+    }
+
+    pub fn view_size(&self) -> (i32, i32) {
+        (self.view_width, self.view_height)
+    }
+
+    /// Оновлює розмір вюпорту (викликається при зміні розміру терміналу).
+    pub fn set_viewport(&mut self, width: i32, height: i32, map: &Map) {
+        self.view_width = width.max(0);
+        self.view_height = height.max(0);
+        self.clamp(map);
+    }
+
+    pub fn scroll(&mut self, dx: i32, dy: i32, map: &Map) {
+        self.x += dx;
+        self.y += dy;
+        self.clamp(map);
+    }
+
+    pub fn center_on(&mut self, pos: MapPosition, map: &Map) {
+        self.x = pos.x() - self.view_width / 2;
+        self.y = pos.y() - self.view_height / 2;
+        self.clamp(map);
+    }
+
+    /// Мінімально зсуває камеру, щоб `pos` опинилась у кадрі.
+    pub fn ensure_visible(&mut self, pos: MapPosition, map: &Map) {
+        if self.view_width > 0 {
+            if pos.x() < self.x { self.x = pos.x(); }
+            if pos.x() >= self.x + self.view_width { self.x = pos.x() - self.view_width + 1; }
+        }
+        if self.view_height > 0 {
+            if pos.y() < self.y { self.y = pos.y(); }
+            if pos.y() >= self.y + self.view_height { self.y = pos.y() - self.view_height + 1; }
+        }
+        self.clamp(map);
+    }
+
+    /// Перетворює координати всередині вюпорту (0,0 = лівий верхній кут) на світові.
+    pub fn screen_to_world(&self, sx: i32, sy: i32) -> MapPosition {
+        MapPosition::new(sx + self.x, sy + self.y)
+    }
+
+    pub fn is_visible(&self, pos: MapPosition) -> bool {
+        pos.x() >= self.x && pos.x() < self.x + self.view_width
+            && pos.y() >= self.y && pos.y() < self.y + self.view_height
+    }
+
+    fn clamp(&mut self, map: &Map) {
+        let max_x = (map.width() - self.view_width).max(0);
+        let max_y = (map.height() - self.view_height).max(0);
+        self.x = self.x.clamp(0, max_x);
+        self.y = self.y.clamp(0, max_y);
+    }
+}
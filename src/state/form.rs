@@ -0,0 +1,253 @@
+// src/state/form.rs
+
+use crate::state::actions::FormKey;
+
+/// Тип поля і його обмеження (перевіряються при `validate`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    Text { max_len: usize },
+    Char,
+    Number { min: u32, max: u32 },
+    Bool,
+}
+
+/// Розібране значення поля.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldValue {
+    Text(String),
+    Char(char),
+    Number(u32),
+    Bool(bool),
+}
+
+impl FieldValue {
+    fn to_buffer(&self) -> String {
+        match self {
+            FieldValue::Text(s) => s.clone(),
+            FieldValue::Char(c) => c.to_string(),
+            FieldValue::Number(n) => n.to_string(),
+            FieldValue::Bool(b) => bool_label(*b).to_string(),
+        }
+    }
+}
+
+fn bool_label(b: bool) -> &'static str {
+    if b { "ON" } else { "OFF" }
+}
+
+/// Одне поле форми; `K` — ідентифікатор, яким поле знаходить власник форми.
+#[derive(Debug, Clone)]
+pub struct FormField<K> {
+    key: K,
+    label: String,
+    kind: FieldKind,
+    buffer: String,
+    initial: String,
+    /// Позиція курсора в символах (не байтах).
+    cursor: usize,
+    error: Option<String>,
+}
+
+impl<K: Copy + Eq> FormField<K> {
+    pub fn key(&self) -> K { self.key }
+    pub fn label(&self) -> &str { &self.label }
+    pub fn kind(&self) -> FieldKind { self.kind }
+    pub fn buffer(&self) -> &str { &self.buffer }
+    pub fn cursor(&self) -> usize { self.cursor }
+    pub fn error(&self) -> Option<&str> { self.error.as_deref() }
+
+    /// Чи відрізняється введене від початкового значення.
+    pub fn is_modified(&self) -> bool {
+        self.buffer != self.initial
+    }
+
+    fn len(&self) -> usize {
+        self.buffer.chars().count()
+    }
+
+    fn byte_index(&self, char_idx: usize) -> usize {
+        self.buffer.char_indices().nth(char_idx).map_or(self.buffer.len(), |(i, _)| i)
+    }
+
+    fn insert(&mut self, c: char) {
+        match self.kind {
+            FieldKind::Text { max_len } => {
+                if c.is_control() || self.len() >= max_len { return; }
+            }
+            // Символ — рівно один знак, новий замінює попередній
+            FieldKind::Char => {
+                if !c.is_control() && !c.is_whitespace() {
+                    self.buffer = c.to_string();
+                    self.cursor = 1;
+                }
+                return;
+            }
+            FieldKind::Number { .. } => {
+                if !c.is_ascii_digit() { return; }
+            }
+            FieldKind::Bool => {
+                match c.to_ascii_lowercase() {
+                    'y' | 't' | '1' => self.buffer = bool_label(true).to_string(),
+                    'n' | 'f' | '0' => self.buffer = bool_label(false).to_string(),
+                    ' ' => self.toggle(),
+                    _ => {}
+                }
+                self.cursor = self.len();
+                return;
+            }
+        }
+        let at = self.byte_index(self.cursor);
+        self.buffer.insert(at, c);
+        self.cursor += 1;
+    }
+
+    fn toggle(&mut self) {
+        let on = self.buffer == bool_label(true);
+        self.buffer = bool_label(!on).to_string();
+    }
+
+    fn backspace(&mut self) {
+        if self.kind == FieldKind::Bool || self.cursor == 0 { return; }
+        self.cursor -= 1;
+        let at = self.byte_index(self.cursor);
+        self.buffer.remove(at);
+    }
+
+    fn delete(&mut self) {
+        if self.kind == FieldKind::Bool || self.cursor >= self.len() { return; }
+        let at = self.byte_index(self.cursor);
+        self.buffer.remove(at);
+    }
+
+    /// Перевіряє буфер і повертає значення або текст помилки.
+    pub fn validate(&self) -> Result<FieldValue, String> {
+        let text = self.buffer.trim();
+        match self.kind {
+            FieldKind::Text { .. } => {
+                if text.is_empty() {
+                    return Err(format!("{} can't be empty", self.label.trim()));
+                }
+                Ok(FieldValue::Text(text.to_string()))
+            }
+            FieldKind::Char => match text.chars().next() {
+                Some(c) => Ok(FieldValue::Char(c)),
+                None => Err(format!("{} needs one character", self.label.trim())),
+            },
+            FieldKind::Number { min, max } => match text.parse::<u32>() {
+                Ok(n) if (min..=max).contains(&n) => Ok(FieldValue::Number(n)),
+                _ => Err(format!("{} must be {}..{}", self.label.trim(), min, max)),
+            },
+            FieldKind::Bool => Ok(FieldValue::Bool(text == bool_label(true))),
+        }
+    }
+}
+
+/// Форма з кількох полів: фокус, курсор, Tab між полями, перевірка значень.
+#[derive(Debug, Clone)]
+pub struct Form<K> {
+    fields: Vec<FormField<K>>,
+    focus: usize,
+}
+
+impl<K: Copy + Eq> Default for Form<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Copy + Eq> Form<K> {
+    pub fn new() -> Self {
+        Form { fields: Vec::new(), focus: 0 }
+    }
+
+    /// Додає поле з початковим значенням (builder-стиль).
+    pub fn with_field(mut self, key: K, label: &str, kind: FieldKind, value: FieldValue) -> Self {
+        let buffer = value.to_buffer();
+        self.fields.push(FormField {
+            key,
+            label: label.to_string(),
+            kind,
+            cursor: buffer.chars().count(),
+            initial: buffer.clone(),
+            buffer,
+            error: None,
+        });
+        self
+    }
+
+    pub fn fields(&self) -> &[FormField<K>] { &self.fields }
+
+    pub fn field(&self, key: K) -> Option<&FormField<K>> {
+        self.fields.iter().find(|f| f.key == key)
+    }
+
+    pub fn focused(&self) -> Option<&FormField<K>> {
+        self.fields.get(self.focus)
+    }
+
+    pub fn is_focused(&self, key: K) -> bool {
+        self.focused().is_some_and(|f| f.key == key)
+    }
+
+    /// Переводить фокус на поле з ключем `key`; `false`, якщо такого поля немає.
+    pub fn focus(&mut self, key: K) -> bool {
+        match self.fields.iter().position(|f| f.key == key) {
+            Some(i) => { self.focus = i; true }
+            None => false,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: FormKey) {
+        let count = self.fields.len();
+        if count == 0 { return; }
+
+        match key {
+            FormKey::NextField => self.focus = (self.focus + 1) % count,
+            FormKey::PrevField => self.focus = (self.focus + count - 1) % count,
+            _ => {
+                let field = &mut self.fields[self.focus];
+                match key {
+                    FormKey::Char(c) => field.insert(c),
+                    FormKey::Backspace => field.backspace(),
+                    FormKey::Delete => field.delete(),
+                    FormKey::Left => field.cursor = field.cursor.saturating_sub(1),
+                    FormKey::Right => field.cursor = (field.cursor + 1).min(field.len()),
+                    FormKey::Home => field.cursor = 0,
+                    FormKey::End => field.cursor = field.len(),
+                    FormKey::NextField | FormKey::PrevField => unreachable!(),
+                }
+                field.error = None;
+            }
+        }
+    }
+
+    /// Перевіряє всі поля. Помилки запам'ятовуються біля полів, фокус
+    /// переходить на перше невалідне; повертається текст першої помилки.
+    pub fn validate(&mut self) -> Result<Vec<(K, FieldValue)>, String> {
+        let mut values = Vec::new();
+        let mut first_error: Option<(usize, String)> = None;
+
+        for (i, field) in self.fields.iter_mut().enumerate() {
+            match field.validate() {
+                Ok(v) => { field.error = None; values.push((field.key, v)); }
+                Err(e) => {
+                    field.error = Some(e.clone());
+                    first_error.get_or_insert((i, e));
+                }
+            }
+        }
+
+        match first_error {
+            Some((i, e)) => { self.focus = i; Err(e) }
+            None => Ok(values),
+        }
+    }
+
+    /// Як `validate`, але лишає тільки змінені поля.
+    pub fn modified_values(&mut self) -> Result<Vec<(K, FieldValue)>, String> {
+        let values = self.validate()?;
+        Ok(values.into_iter()
+            .filter(|(key, _)| self.field(*key).is_some_and(|f| f.is_modified()))
+            .collect())
+    }
+}
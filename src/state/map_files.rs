@@ -0,0 +1,54 @@
+// src/state/map_files.rs
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Змінна середовища з теками карт; якщо не задана — поточна тека.
+pub const MAPS_DIR_ENV: &str = "TELOS_MAPS_DIR";
+/// Розширення файлів карт.
+pub const MAP_EXTENSION: &str = "json";
+
+pub fn maps_dir() -> PathBuf {
+    env::var_os(MAPS_DIR_ENV)
+        .filter(|dir| !dir.is_empty())
+        .map_or_else(|| PathBuf::from("."), PathBuf::from)
+}
+
+/// Імена файлів карт у теці, відсортовані за абеткою.
+pub fn list_maps(dir: &Path) -> io::Result<Vec<String>> {
+    let mut names: Vec<String> = fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_file())
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == MAP_EXTENSION))
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
+/// Перевіряє ім'я, введене користувачем, і додає розширення, якщо його немає.
+pub fn file_name(name: &str) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("File name can't be empty".to_string());
+    }
+    if name.contains(['/', '\\']) || name.starts_with('.') {
+        return Err("Use a plain file name (no folders)".to_string());
+    }
+    if Path::new(name).extension().is_some_and(|ext| ext == MAP_EXTENSION) {
+        Ok(name.to_string())
+    } else {
+        Ok(format!("{}.{}", name, MAP_EXTENSION))
+    }
+}
+
+/// Повний шлях до карти з іменем `name` у теці карт.
+pub fn map_path(name: &str) -> String {
+    let dir = maps_dir();
+    if dir == Path::new(".") {
+        return name.to_string();
+    }
+    dir.join(name).to_string_lossy().into_owned()
+}
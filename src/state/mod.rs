@@ -2,7 +2,11 @@ pub mod actions;
 pub mod world_state;
 pub mod modes;
 pub mod application_state;
+pub mod map_files;
+pub mod save_format;
+pub mod camera;
+pub mod form;
 
-pub use world_state::WorldState;
+pub use world_state::{WorldState, CellSnapshot};
 pub use application_state::{ApplicationState, AppState};
 pub use actions::{Action, MenuSelection};
\ No newline at end of file
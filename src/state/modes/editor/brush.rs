@@ -0,0 +1,79 @@
+// src/state/modes/editor/brush.rs
+
+use std::collections::{HashSet, VecDeque};
+
+use crate::map::map::Map;
+use crate::map::position::MapPosition;
+use crate::state::actions::BrushShape;
+
+/// Клітинки фігури від `from` до `to` (поза картою відкидаються).
+/// Для `Fill` важливий лише `from`; `Freehand` дає відрізок між точками.
+pub fn shape_cells(shape: BrushShape, map: &Map, from: MapPosition, to: MapPosition) -> Vec<MapPosition> {
+    let cells = match shape {
+        BrushShape::Freehand | BrushShape::Line => line(from, to),
+        BrushShape::Rect => rect(from, to, true),
+        BrushShape::HollowRect => rect(from, to, false),
+        BrushShape::Fill => flood_fill(map, from),
+    };
+    cells.into_iter().filter(|p| map.in_bounds(*p)).collect()
+}
+
+/// Відрізок Брезенхема, включно з обома кінцями.
+pub fn line(from: MapPosition, to: MapPosition) -> Vec<MapPosition> {
+    let (mut x, mut y) = (from.x(), from.y());
+    let dx = (to.x() - x).abs();
+    let dy = -(to.y() - y).abs();
+    let sx = if x < to.x() { 1 } else { -1 };
+    let sy = if y < to.y() { 1 } else { -1 };
+    let mut err = dx + dy;
+
+    let mut cells = vec![MapPosition::new(x, y)];
+    while (x, y) != (to.x(), to.y()) {
+        let e2 = 2 * err;
+        if e2 >= dy { err += dy; x += sx; }
+        if e2 <= dx { err += dx; y += sy; }
+        cells.push(MapPosition::new(x, y));
+    }
+    cells
+}
+
+/// Прямокутник між двома кутами; `filled = false` — лише периметр.
+pub fn rect(a: MapPosition, b: MapPosition, filled: bool) -> Vec<MapPosition> {
+    let (x0, x1) = (a.x().min(b.x()), a.x().max(b.x()));
+    let (y0, y1) = (a.y().min(b.y()), a.y().max(b.y()));
+
+    let mut cells = Vec::new();
+    for y in y0..=y1 {
+        for x in x0..=x1 {
+            let edge = x == x0 || x == x1 || y == y0 || y == y1;
+            if filled || edge {
+                cells.push(MapPosition::new(x, y));
+            }
+        }
+    }
+    cells
+}
+
+/// Зв'язна (4-сусіди) область тайлів того ж типу, що й у `start`.
+pub fn flood_fill(map: &Map, start: MapPosition) -> Vec<MapPosition> {
+    let target = match map.get_tile(start) {
+        Some(t) => t.tile_type(),
+        None => return Vec::new(),
+    };
+
+    let mut seen = HashSet::from([start]);
+    let mut queue = VecDeque::from([start]);
+    let mut cells = Vec::new();
+
+    while let Some(pos) = queue.pop_front() {
+        cells.push(pos);
+        for n in pos.neighbors() {
+            if seen.contains(&n) { continue; }
+            if map.get_tile(n).is_some_and(|t| t.tile_type() == target) {
+                seen.insert(n);
+                queue.push_back(n);
+            }
+        }
+    }
+    cells
+}
@@ -1,27 +1,93 @@
-use crate::state::world_state::WorldState; 
-use crate::state::actions::{BuildTool, Action}; 
+use crate::state::world_state::{WorldState, DEFAULT_MAP_PATH}; 
+use crate::state::map_files::map_path;
+use crate::state::actions::{BuildTool, BrushShape, FormKey, Action}; 
 use crate::map::position::MapPosition;
+use crate::specials::powerup::PowerupType;
+use crate::specials::entity::{Entity, EntityID};
+use crate::state::camera::Camera;
+use crate::state::form::Form;
 
 // Локальні модулі
-use super::input::{EditorInput, InputTarget};
+use super::input::{EditorInput, InputTarget, FIELD_ORDER, FIELD_ROW};
+use super::map_dialog::{MapDialog, MapDialogKind};
+use super::save_dialog::SaveAsDialog;
 use super::entity_spec::EntitySpec;
+use super::history::{Edit, EditHistory, PendingEdit};
+use super::brush::shape_cells;
+
+/// Мишачий мазок пензлем, що триває (між натисканням і відпусканням).
+struct Stroke {
+    tool: BuildTool,
+    brush: BrushShape,
+    start: MapPosition,
+    last: MapPosition,
+    /// Для Freehand клітинки фарбуються одразу, тож "до" збирається по ходу.
+    pending: PendingEdit,
+}
+
+/// Модальний діалог редактора (поки він відкритий, карта не реагує).
+pub enum EditorDialog {
+    MapSize(MapDialog),
+    SaveAs(SaveAsDialog),
+}
 
 pub struct EditorMode {
     world_state: WorldState,       
+    /// Куди зберігається карта (для відкритої — той самий файл).
+    save_path: String,
     current_tool: BuildTool,       
     debug_message: String,         
     entity_spec: EntitySpec,       
     input_manager: EditorInput,    
+    powerup_type: PowerupType,
+    camera: Camera,
+    cursor: MapPosition,
+    history: EditHistory,
+    brush: BrushShape,
+    /// Перший кут фігури, поставлений з клавіатури (Enter), чекає на другий.
+    anchor: Option<MapPosition>,
+    stroke: Option<Stroke>,
+    /// Юніт, вибраний інструментом Select (його поля редагуються замість спеку).
+    selected: Option<EntityID>,
+    dialog: Option<EditorDialog>,
+}
+
+impl Default for EditorMode {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl EditorMode {
     pub fn new() -> Self {
+        let mut editor = Self::from_world(WorldState::new(), &map_path(DEFAULT_MAP_PATH));
+        editor.debug_message = String::from("Editor: Use W/F/U keys or click menu");
+        editor
+    }
+
+    /// Відкриває вже існуючий світ: ID сутностей зберігаються,
+    /// нові юніти продовжують нумерацію, а збереження йде у `path`.
+    pub fn from_world(mut world_state: WorldState, path: &str) -> Self {
+        // Захист від старих/ручних файлів, де лічильник відстає від реальних ID
+        let min_next_id = world_state.entities.iter().map(|e| e.id() + 1).max().unwrap_or(0);
+        world_state.next_entity_id = world_state.next_entity_id.max(min_next_id);
+
         EditorMode {
-            world_state: WorldState::new(),
+            world_state,
+            save_path: path.to_string(),
             current_tool: BuildTool::Wall,
-            debug_message: String::from("Editor: Use W/F/U keys or click menu"),
+            debug_message: format!("Editing '{}'", path),
             entity_spec: EntitySpec::default(),
             input_manager: EditorInput::new(),
+            powerup_type: PowerupType::HealingPotion,
+            camera: Camera::default(),
+            cursor: MapPosition::new(0, 0),
+            history: EditHistory::new(),
+            brush: BrushShape::Freehand,
+            anchor: None,
+            stroke: None,
+            selected: None,
+            dialog: None,
         }
     }
 
@@ -30,22 +96,77 @@ impl EditorMode {
     // =========================================================================
     
     pub fn world(&self) -> &WorldState { &self.world_state }
+    pub fn save_path(&self) -> &str { &self.save_path }
     
     pub fn current_tool(&self) -> BuildTool { self.current_tool }
     pub fn debug_message(&self) -> &str { &self.debug_message }
     pub fn entity_spec(&self) -> &EntitySpec { &self.entity_spec }
-    pub fn input_target(&self) -> InputTarget { self.input_manager.input_target }
-    pub fn input_buffer(&self) -> &str { &self.input_manager.input_buffer }
+    pub fn powerup_type(&self) -> PowerupType { self.powerup_type }
+    pub fn camera(&self) -> &Camera { &self.camera }
+    pub fn cursor(&self) -> MapPosition { self.cursor }
+    pub fn brush(&self) -> BrushShape { self.brush }
+    pub fn input_target(&self) -> InputTarget { self.input_manager.target() }
+    pub fn input_form(&self) -> Option<&Form<InputTarget>> { self.input_manager.form() }
+    pub fn dialog(&self) -> Option<&EditorDialog> { self.dialog.as_ref() }
+
+    /// Чи йде зараз введення тексту (форма полів або діалог карти).
+    pub fn is_typing(&self) -> bool {
+        self.input_manager.form().is_some() || self.dialog.is_some()
+    }
+
+    /// Вибраний юніт, якщо активний інструмент Select.
+    pub fn selected_unit(&self) -> Option<&Entity> {
+        if self.current_tool != BuildTool::Select {
+            return None;
+        }
+        self.selected.and_then(|id| self.world_state.get_entity(id))
+    }
 
     // =========================================================================
     //                            LOGIC & ACTIONS
     // =========================================================================
 
     pub fn save_map(&mut self) {
-        if let Err(e) = self.world_state.save("map.json") {
+        if let Err(e) = self.world_state.save(&self.save_path) {
             self.debug_message = format!("Save Failed: {}", e);
         } else {
-            self.debug_message = String::from("Saved to 'map.json'!");
+            self.debug_message = format!("Saved to '{}'!", self.save_path);
+        }
+    }
+
+    fn open_save_as(&mut self) {
+        self.dialog = Some(EditorDialog::SaveAs(SaveAsDialog::new(&self.save_path)));
+        self.debug_message = "Type a file name, Enter: save, Esc: cancel".to_string();
+    }
+
+    /// Enter у "Save As": питає про перезапис або зберігає і робить файл поточним.
+    fn confirm_save_as(&mut self) {
+        let Some(EditorDialog::SaveAs(dialog)) = self.dialog.as_mut() else { return };
+        match dialog.target(&self.save_path) {
+            Ok(Some(path)) => {
+                self.dialog = None;
+                self.save_path = path;
+                self.save_map();
+            }
+            Ok(None) => self.debug_message = "File exists: Enter again to overwrite".to_string(),
+            Err(msg) => self.debug_message = msg,
+        }
+    }
+
+    pub fn scroll_camera(&mut self, dx: i32, dy: i32) {
+        self.camera.scroll(dx, dy, &self.world_state.map);
+    }
+
+    pub fn set_viewport(&mut self, width: i32, height: i32) {
+        self.camera.set_viewport(width, height, &self.world_state.map);
+    }
+
+    /// Зсуває клавіатурний курсор у межах карти; камера стежить за ним.
+    pub fn move_cursor(&mut self, dx: i32, dy: i32) {
+        let next = self.cursor.offset(dx, dy);
+        if self.world_state.map.in_bounds(next) {
+            self.cursor = next;
+            self.camera.ensure_visible(next, &self.world_state.map);
         }
     }
 
@@ -53,25 +174,264 @@ impl EditorMode {
         self.current_tool = match self.current_tool {
             BuildTool::Wall => BuildTool::Floor,
             BuildTool::Floor => BuildTool::Unit,
-            BuildTool::Unit => BuildTool::Wall,
+            BuildTool::Unit => BuildTool::Powerup,
+            BuildTool::Powerup => BuildTool::Select,
+            BuildTool::Select => BuildTool::Wall,
         };
         self.debug_message = format!("Tool: {:?}", self.current_tool);
     }
 
     fn set_tool(&mut self, tool: BuildTool) {
         self.current_tool = tool;
+        self.anchor = None;
         self.debug_message = format!("Tool: {:?}", self.current_tool);
     }
+
+    fn cycle_brush(&mut self) {
+        self.brush = self.brush.next();
+        self.anchor = None;
+        self.debug_message = format!("Brush: {}", self.brush.name());
+    }
+
+    /// Клітинки фігури, яку зараз тягнуть мишкою або розмічають з клавіатури.
+    pub fn stroke_preview(&self) -> Vec<MapPosition> {
+        if !self.uses_shape() {
+            return Vec::new();
+        }
+        let (from, to) = match (&self.stroke, self.anchor) {
+            (Some(s), _) => (s.start, s.last),
+            (None, Some(a)) => (a, self.cursor),
+            _ => return Vec::new(),
+        };
+        shape_cells(self.brush, &self.world_state.map, from, to)
+    }
+
+    /// Інструменти, що діють на одну клітинку і ігнорують пензель.
+    fn is_point_tool(&self) -> bool {
+        matches!(self.current_tool, BuildTool::Unit | BuildTool::Select)
+    }
+
+    /// Чи малює поточний інструмент двоточковою фігурою (Line / Rect).
+    fn uses_shape(&self) -> bool {
+        !self.is_point_tool()
+            && matches!(self.brush, BrushShape::Line | BrushShape::Rect | BrushShape::HollowRect)
+    }
+
+    /// Перший вибір — інструмент Powerup, повторний — наступний тип паверапа.
+    fn select_powerup_tool(&mut self) {
+        if self.current_tool == BuildTool::Powerup {
+            self.powerup_type = self.powerup_type.next();
+        }
+        self.current_tool = BuildTool::Powerup;
+        self.debug_message = format!("Tool: Powerup ({})", self.powerup_type.name());
+    }
     
     // --- INPUT HANDLERS ---
 
     pub fn handle_click(&mut self, pos: MapPosition) {
-        if self.input_manager.input_target != InputTarget::None {
+        if self.dialog.is_some() { return; }
+        if self.world_state.map.in_bounds(pos) {
+            self.cursor = pos;
+        }
+
+        if self.input_manager.form().is_some() {
+            self.confirm_input();
+            return;
+        }
+
+        if self.is_point_tool() || self.brush == BrushShape::Freehand {
+            self.paint_cells(vec![pos]);
+        } else if self.brush == BrushShape::Fill {
+            self.paint_cells(shape_cells(BrushShape::Fill, &self.world_state.map, pos, pos));
+        } else if let Some(anchor) = self.anchor.take() {
+            self.paint_cells(shape_cells(self.brush, &self.world_state.map, anchor, pos));
+        } else {
+            self.anchor = Some(pos);
+            self.debug_message = format!("{}: first corner set, pick the second", self.brush.name());
+        }
+    }
+
+    // --- MOUSE STROKES ---
+
+    pub fn stroke_start(&mut self, pos: MapPosition) {
+        if self.dialog.is_some() { return; }
+        if self.input_manager.form().is_some() {
             self.confirm_input();
             return;
         }
+        if self.world_state.map.in_bounds(pos) {
+            self.cursor = pos;
+        }
 
-        match self.current_tool {
+        // Юніти, вибір і заливка спрацьовують одразу, без перетягування
+        if self.is_point_tool() || self.brush == BrushShape::Fill {
+            self.anchor = None;
+            self.handle_click(pos);
+            return;
+        }
+
+        // Якщо відпускання мишки загубилось, попередній мазок не має пропасти з історії
+        if let Some(stroke) = self.stroke.take() {
+            self.finish_stroke(stroke);
+        }
+
+        self.anchor = None;
+        self.stroke = Some(Stroke {
+            tool: self.current_tool,
+            brush: self.brush,
+            start: pos,
+            last: pos,
+            pending: PendingEdit::default(),
+        });
+        if self.brush == BrushShape::Freehand {
+            self.paint_stroke_segment(pos, pos);
+        }
+    }
+
+    pub fn stroke_move(&mut self, pos: MapPosition) {
+        let (last, brush) = match &self.stroke {
+            Some(s) => (s.last, s.brush),
+            None => return,
+        };
+        if brush == BrushShape::Freehand {
+            // Заповнюємо проміжок, якщо мишка "перестрибнула" клітинки
+            self.paint_stroke_segment(last, pos);
+        }
+        if let Some(s) = self.stroke.as_mut() {
+            s.last = pos;
+        }
+        if self.world_state.map.in_bounds(pos) {
+            self.cursor = pos;
+        }
+    }
+
+    pub fn stroke_end(&mut self, pos: MapPosition) {
+        self.stroke_move(pos);
+        if let Some(stroke) = self.stroke.take() {
+            self.finish_stroke(stroke);
+        }
+    }
+
+    /// Кладе мазок в історію: Freehand уже намальований, фігура малюється зараз.
+    fn finish_stroke(&mut self, stroke: Stroke) {
+        let label = format!("{:?} {}", stroke.tool, stroke.brush.name());
+        if stroke.brush == BrushShape::Freehand {
+            let edit = stroke.pending.finish(label, &self.world_state);
+            self.history.push(edit);
+        } else {
+            let cells = shape_cells(stroke.brush, &self.world_state.map, stroke.start, stroke.last);
+            self.record_cells(&label, &cells, |ed| {
+                for &cell in &cells {
+                    ed.apply_tool(stroke.tool, cell);
+                }
+            });
+            self.debug_message = format!("{}: {} cells", label, cells.len());
+        }
+    }
+
+    fn paint_stroke_segment(&mut self, from: MapPosition, to: MapPosition) {
+        let Some(tool) = self.stroke.as_ref().map(|s| s.tool) else { return };
+        for cell in shape_cells(BrushShape::Line, &self.world_state.map, from, to) {
+            if let Some(s) = self.stroke.as_mut() {
+                s.pending.touch(&self.world_state, cell);
+            }
+            self.apply_tool(tool, cell);
+        }
+    }
+
+    /// Застосовує інструмент до клітинок одним кроком історії.
+    fn paint_cells(&mut self, cells: Vec<MapPosition>) {
+        let tool = self.current_tool;
+        let label = if cells.len() == 1 {
+            format!("{:?}", tool)
+        } else {
+            format!("{:?} {}", tool, self.brush.name())
+        };
+        self.record_cells(&label, &cells, |ed| {
+            for &cell in &cells {
+                ed.apply_tool(tool, cell);
+            }
+        });
+        if cells.len() > 1 {
+            self.debug_message = format!("{}: {} cells", label, cells.len());
+        }
+    }
+
+    /// Ctrl+Z: відкочує останню операцію.
+    pub fn undo(&mut self) {
+        self.debug_message = match self.history.undo() {
+            Some(edit) => {
+                edit.revert(&mut self.world_state, &mut self.entity_spec);
+                format!("Undo: {}", edit.label())
+            }
+            None => "Nothing to undo".to_string(),
+        };
+        self.fit_view();
+    }
+
+    /// Ctrl+Y: повторює відкочену операцію.
+    pub fn redo(&mut self) {
+        self.debug_message = match self.history.redo() {
+            Some(edit) => {
+                edit.apply(&mut self.world_state, &mut self.entity_spec);
+                format!("Redo: {}", edit.label())
+            }
+            None => "Nothing to redo".to_string(),
+        };
+        self.fit_view();
+    }
+
+    // --- MAP SIZE ---
+
+    fn open_map_dialog(&mut self, kind: MapDialogKind) {
+        let map = &self.world_state.map;
+        self.dialog = Some(EditorDialog::MapSize(MapDialog::new(kind, map.width(), map.height())));
+        self.debug_message = "Tab: next field, Enter: apply, Esc: cancel".to_string();
+    }
+
+    /// Enter у діалозі: створює нову карту або змінює розмір поточної (з undo).
+    fn confirm_map_dialog(&mut self) {
+        let Some(EditorDialog::MapSize(dialog)) = self.dialog.as_mut() else { return };
+        let (width, height) = match dialog.size() {
+            Ok(size) => size,
+            Err(msg) => { self.debug_message = msg; return; }
+        };
+        let kind = dialog.kind();
+        self.dialog = None;
+
+        let before = self.world_state.clone();
+        let label = match kind {
+            MapDialogKind::New => {
+                self.world_state = WorldState::with_size(width, height);
+                self.debug_message = format!("New map {}x{}", width, height);
+                "New Map"
+            }
+            MapDialogKind::Resize => {
+                let report = self.world_state.resize(width, height);
+                self.debug_message = format!(
+                    "Resized to {}x{}: {} units moved, {} removed",
+                    width, height, report.relocated.len(), report.removed.len()
+                );
+                "Resize Map"
+            }
+        };
+        self.history.push(Edit::world(label.to_string(), before, self.world_state.clone()));
+        self.anchor = None;
+        self.fit_view();
+    }
+
+    /// Тримає курсор і камеру в межах карти після зміни її розміру.
+    fn fit_view(&mut self) {
+        let map = &self.world_state.map;
+        self.cursor = MapPosition::new(
+            self.cursor.x().clamp(0, (map.width() - 1).max(0)),
+            self.cursor.y().clamp(0, (map.height() - 1).max(0)),
+        );
+        self.camera.scroll(0, 0, map);
+    }
+
+    fn apply_tool(&mut self, tool: BuildTool, pos: MapPosition) {
+        match tool {
             BuildTool::Wall => {
                 if self.world_state.build_wall(pos) {
                     self.debug_message = format!("Built Wall at {:?}", pos);
@@ -82,6 +442,10 @@ impl EditorMode {
                 self.debug_message = format!("Cleared at {:?}", pos);
             }
             BuildTool::Unit => {
+                if !self.world_state.map.is_standable(pos) {
+                    self.debug_message = "Units need a free floor tile".to_string();
+                    return;
+                }
                 let id = self.world_state.next_id();
                 let new_entity = self.entity_spec.to_entity(id, pos);
                 let name = new_entity.display_name().to_string(); 
@@ -89,11 +453,54 @@ impl EditorMode {
                 self.world_state.add_entity(new_entity);
                 self.debug_message = format!("Spawned {} #{}", name, id);
             }
+            BuildTool::Powerup => {
+                if self.world_state.place_powerup(pos, self.powerup_type) {
+                    self.debug_message = format!("Placed {} at {:?}", self.powerup_type.name(), pos);
+                } else {
+                    self.debug_message = "Powerups need a free floor tile".to_string();
+                }
+            }
+            BuildTool::Select => {
+                self.selected = self.world_state.get_entity_id_at(pos);
+                self.debug_message = match self.selected.and_then(|id| self.world_state.get_entity(id)) {
+                    Some(e) => format!("Selected {} #{}", e.display_name(), e.id()),
+                    None => "No unit here".to_string(),
+                };
+            }
+        }
+    }
+
+    // --- HISTORY ---
+
+    /// Виконує операцію над клітинками `cells` і кладе в історію лише ті, що змінились.
+    fn record_cells<F: FnOnce(&mut Self)>(&mut self, label: &str, cells: &[MapPosition], op: F) {
+        let mut pending = PendingEdit::default();
+        for &pos in cells {
+            pending.touch(&self.world_state, pos);
+        }
+        op(self);
+        self.history.push(pending.finish(label.to_string(), &self.world_state));
+    }
+
+    /// Змінює вибраний юніт; в історію потрапляє його клітинка.
+    fn record_unit<F: FnOnce(&mut Self, EntityID)>(&mut self, label: &str, op: F) -> bool {
+        let Some(unit) = self.selected_unit() else { return false };
+        let (id, pos) = (unit.id(), unit.position());
+        self.record_cells(label, &[pos], |ed| op(ed, id));
+        true
+    }
+
+    /// Виконує зміну спеку юніта і кладе її в історію, якщо спек змінився.
+    fn record_spec<F: FnOnce(&mut Self)>(&mut self, label: &str, op: F) {
+        let before = self.entity_spec.clone();
+        op(self);
+        if before != self.entity_spec {
+            self.history.push(Edit::spec(label.to_string(), before, self.entity_spec.clone()));
         }
     }
 
     pub fn handle_keypress(&mut self, key: char) -> Option<Action> {
-        if self.input_manager.input_target != InputTarget::None {
+        if self.is_typing() {
             return None; 
         }
 
@@ -101,69 +508,138 @@ impl EditorMode {
             'w' | 'W' => self.set_tool(BuildTool::Wall),
             'f' | 'F' => self.set_tool(BuildTool::Floor),
             'u' | 'U' => self.set_tool(BuildTool::Unit),
+            'p' | 'P' => self.select_powerup_tool(),
+            'i' | 'I' => self.set_tool(BuildTool::Select),
+            'b' | 'B' => self.cycle_brush(),
             's' | 'S' => self.save_map(),
+            'v' | 'V' => self.open_save_as(),
+            'm' | 'M' => self.open_map_dialog(MapDialogKind::New),
+            'x' | 'X' => self.open_map_dialog(MapDialogKind::Resize),
             'q' | 'Q' => return Some(Action::BackToMenu),
             
             't' | 'T' => self.start_input(InputTarget::Team),
             'h' | 'H' => self.start_input(InputTarget::Hp),
             'e' | 'E' => self.start_input(InputTarget::Energy),
             'd' | 'D' => self.start_input(InputTarget::Damage),
-            'a' | 'A' => {
-                self.entity_spec.toggle_ai();
-                self.debug_message = format!("AI toggled: {}", self.entity_spec.is_ai);
-            }
+            'r' | 'R' => self.start_input(InputTarget::Range),
+            'n' | 'N' => self.start_input(InputTarget::Name),
+            'y' | 'Y' => self.start_input(InputTarget::Symbol),
+            'a' | 'A' => self.toggle_ai(),
             _ => {}
         }
         None
     }
 
     pub fn handle_menu_click(&mut self, _screen_x: i32, screen_y: i32) -> Option<Action> {
-        if self.input_manager.input_target != InputTarget::None {
+        if self.dialog.is_some() { return None; }
+
+        let field = usize::try_from(screen_y - FIELD_ROW).ok().and_then(|i| FIELD_ORDER.get(i)).copied();
+        if self.input_manager.form().is_some() {
+            // Клік по рядку відкритої форми лише переводить фокус
+            if field.is_some_and(|target| self.input_manager.focus(target)) {
+                return None;
+            }
             self.confirm_input();
+            if self.input_manager.form().is_some() {
+                return None;
+            }
         }
 
         match screen_y {
             3 => self.set_tool(BuildTool::Wall),
             4 => self.set_tool(BuildTool::Floor),
             5 => self.set_tool(BuildTool::Unit),
+            6 => self.select_powerup_tool(),
+            7 => self.set_tool(BuildTool::Select),
+            8 => self.cycle_brush(),
             
-            7 => self.save_map(),
+            10 => self.save_map(),
+            11 => self.open_save_as(),
+            12 => self.open_map_dialog(MapDialogKind::New),
+            13 => self.open_map_dialog(MapDialogKind::Resize),
             
-            9 => return Some(Action::BackToMenu),
-
-            12 => self.start_input(InputTarget::Team),
-            13 => self.start_input(InputTarget::Hp),
-            14 => self.start_input(InputTarget::Energy),
-            15 => self.start_input(InputTarget::Damage),
-            16 => {
-                self.entity_spec.toggle_ai();
-                self.debug_message = format!("AI set to {}", self.entity_spec.is_ai);
-            },
+            15 => return Some(Action::BackToMenu),
 
-            _ => {}
+            _ => match field {
+                Some(InputTarget::Ai) => self.toggle_ai(),
+                Some(target) => self.start_input(target),
+                None => {}
+            },
         }
         None
     }
     
     // --- INPUT DELEGATION ---
 
-    pub fn handle_typing(&mut self, c: char) { self.input_manager.handle_typing(c); }
-    pub fn handle_backspace(&mut self) { self.input_manager.handle_backspace(); }
+    pub fn handle_form_key(&mut self, key: FormKey) {
+        match self.dialog.as_mut() {
+            Some(EditorDialog::MapSize(dialog)) => dialog.form_mut().handle_key(key),
+            Some(EditorDialog::SaveAs(dialog)) => dialog.form_mut().handle_key(key),
+            None => self.input_manager.handle_key(key),
+        }
+    }
     
+    /// Enter: перевіряє форму і застосовує змінені поля (одним кроком історії).
     pub fn confirm_input(&mut self) {
-        if let Some(msg) = self.input_manager.confirm(&mut self.entity_spec) {
-            self.debug_message = msg;
+        match self.dialog {
+            Some(EditorDialog::MapSize(_)) => return self.confirm_map_dialog(),
+            Some(EditorDialog::SaveAs(_)) => return self.confirm_save_as(),
+            None => {}
         }
+
+        let edited_unit = self.record_unit("Edit Unit", |ed, id| {
+            if let Some(entity) = ed.world_state.get_entity_mut(id) {
+                ed.debug_message = match ed.input_manager.confirm_entity(entity) {
+                    Ok(msg) | Err(msg) => msg,
+                };
+            }
+        });
+        if edited_unit {
+            return;
+        }
+
+        self.record_spec("Edit Template", |ed| {
+            ed.debug_message = match ed.input_manager.confirm(&mut ed.entity_spec) {
+                Ok(msg) | Err(msg) => msg,
+            };
+        });
     }
 
     pub fn cancel_input(&mut self) {
+        self.dialog = None;
         self.input_manager.cancel();
         self.debug_message = "Edit Cancelled".to_string();
     }
 
+    /// Відкриває форму (для вибраного юніта або шаблону) з фокусом на `target`.
     fn start_input(&mut self, target: InputTarget) {
-        self.input_manager.cancel();
-        self.input_manager.input_target = target;
-        self.debug_message = format!("Edit {:?}: Type number + Enter", target);
+        let unit_id = self.selected_unit().map(|u| u.id());
+        match unit_id.and_then(|id| self.world_state.get_entity(id)) {
+            Some(unit) => self.input_manager.open_entity(unit),
+            None => self.input_manager.open_spec(&self.entity_spec),
+        }
+
+        if self.input_manager.focus(target) {
+            self.debug_message = "Tab: next field, Enter: apply, Esc: cancel".to_string();
+        } else {
+            self.input_manager.cancel();
+            self.debug_message = format!("{:?} applies to placed units only", target);
+        }
+    }
+
+    /// AI вибраного юніта або, якщо його немає, шаблону нових юнітів.
+    fn toggle_ai(&mut self) {
+        let toggled_unit = self.record_unit("Toggle AI", |ed, id| {
+            if let Some(entity) = ed.world_state.get_entity_mut(id) {
+                entity.set_ai(!entity.is_ai());
+                ed.debug_message = format!("#{}: AI {}", id, entity.is_ai());
+            }
+        });
+        if !toggled_unit {
+            self.record_spec("Toggle AI", |ed| {
+                ed.entity_spec.toggle_ai();
+                ed.debug_message = format!("AI set to {}", ed.entity_spec.is_ai);
+            });
+        }
     }
 }
\ No newline at end of file
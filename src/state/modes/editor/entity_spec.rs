@@ -1,7 +1,7 @@
 use crate::specials::entity::{Entity, EntityID};
 use crate::map::position::MapPosition;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EntitySpec {
     pub team: u32,
     pub hp: u32,
@@ -10,6 +10,8 @@ pub struct EntitySpec {
     pub range: u32,
     pub is_ai: bool,
     pub symbol: char,
+    /// `None` — ім'я за командою ("Player" / "Enemy").
+    pub name: Option<String>,
 }
 
 impl Default for EntitySpec {
@@ -22,6 +24,7 @@ impl Default for EntitySpec {
             range: 2,
             is_ai: false,       
             symbol: '@', 
+            name: None,
         }
     }
 }
@@ -42,17 +45,20 @@ impl EntitySpec {
         self.is_ai = !self.is_ai;
     }
 
-    pub fn to_entity(&self, id: EntityID, pos: MapPosition) -> Entity {
-        let name = if self.team == 1 { 
-            "Player".to_string() 
-        } else { 
-            "Enemy".to_string() 
-        };
+    /// Ім'я нових юнітів: задане у формі або типове для команди.
+    pub fn name(&self) -> &str {
+        match &self.name {
+            Some(name) => name,
+            None if self.team == 1 => "Player",
+            None => "Enemy",
+        }
+    }
 
+    pub fn to_entity(&self, id: EntityID, pos: MapPosition) -> Entity {
         let mut entity = Entity::new(
             id,
             self.symbol,
-            name,
+            self.name().to_string(),
             pos,
             self.team,
             self.hp,
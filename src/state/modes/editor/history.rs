@@ -0,0 +1,170 @@
+// src/state/modes/editor/history.rs
+
+use std::collections::{HashSet, VecDeque};
+
+use crate::state::world_state::{WorldState, CellSnapshot};
+use crate::map::position::MapPosition;
+use crate::specials::entity::EntityID;
+use super::entity_spec::EntitySpec;
+
+/// Максимальна кількість кроків undo.
+pub const HISTORY_LIMIT: usize = 100;
+/// Скільки змінених клітинок може зберігати історія загалом
+/// (старі кроки відкидаються, навіть якщо ліміт кроків ще не вичерпано).
+pub const CELL_BUDGET: usize = 20_000;
+
+/// Одна операція редактора у вигляді дифу: лише змінені клітинки і/або спек.
+#[derive(Debug, Clone)]
+pub struct Edit {
+    label: String,
+    /// Пари (до, після) для кожної зміненої клітинки.
+    cells: Vec<(CellSnapshot, CellSnapshot)>,
+    /// Порядок сутностей (до, після): `restore_cell` дописує сутності в кінець,
+    /// а від порядку залежить черговість ходів AI.
+    order: Option<(Vec<EntityID>, Vec<EntityID>)>,
+    spec: Option<(EntitySpec, EntitySpec)>,
+    /// Повні знімки світу (до, після) для операцій над усією картою (розмір, нова карта).
+    world: Option<Box<(WorldState, WorldState)>>,
+}
+
+impl Edit {
+    pub fn cells(label: String, cells: Vec<(CellSnapshot, CellSnapshot)>) -> Self {
+        Edit { label, cells, order: None, spec: None, world: None }
+    }
+
+    pub fn spec(label: String, before: EntitySpec, after: EntitySpec) -> Self {
+        Edit { label, cells: Vec::new(), order: None, spec: Some((before, after)), world: None }
+    }
+
+    pub fn world(label: String, before: WorldState, after: WorldState) -> Self {
+        Edit { label, cells: Vec::new(), order: None, spec: None, world: Some(Box::new((before, after))) }
+    }
+
+    pub fn label(&self) -> &str { &self.label }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty() && self.spec.is_none() && self.world.is_none()
+    }
+
+    /// Скільки клітинок операція займає в бюджеті історії.
+    fn cost(&self) -> usize {
+        let world = self.world.as_ref().map_or(0, |w| {
+            let area = |ws: &WorldState| (ws.map.width() * ws.map.height()) as usize;
+            area(&w.0) + area(&w.1)
+        });
+        self.cells.len() + world
+    }
+
+    /// Повертає світ і спек у стан до операції.
+    pub fn revert(&self, world: &mut WorldState, spec: &mut EntitySpec) {
+        if let Some(snapshots) = &self.world {
+            *world = snapshots.0.clone();
+        }
+        for (before, _) in self.cells.iter().rev() {
+            world.restore_cell(before);
+        }
+        if let Some((before, _)) = &self.order {
+            world.reorder_entities(before);
+        }
+        if let Some((before, _)) = &self.spec {
+            *spec = before.clone();
+        }
+    }
+
+    /// Повторно застосовує операцію.
+    pub fn apply(&self, world: &mut WorldState, spec: &mut EntitySpec) {
+        if let Some(snapshots) = &self.world {
+            *world = snapshots.1.clone();
+        }
+        for (_, after) in self.cells.iter() {
+            world.restore_cell(after);
+        }
+        if let Some((_, after)) = &self.order {
+            world.reorder_entities(after);
+        }
+        if let Some((_, after)) = &self.spec {
+            *spec = after.clone();
+        }
+    }
+}
+
+/// Збирає знімки "до" для операції, що торкається клітинок поступово (drag пензлем).
+#[derive(Debug, Default)]
+pub struct PendingEdit {
+    before: Vec<CellSnapshot>,
+    touched: HashSet<MapPosition>,
+    order: Vec<EntityID>,
+}
+
+impl PendingEdit {
+    /// Запам'ятовує клітинку до першої зміни (повторні виклики ігноруються).
+    pub fn touch(&mut self, world: &WorldState, pos: MapPosition) {
+        if self.touched.is_empty() {
+            self.order = world.entity_order();
+        }
+        if self.touched.insert(pos) {
+            self.before.push(world.snapshot_cell(pos));
+        }
+    }
+
+    /// Порівнює зі станом світу й лишає тільки реально змінені клітинки.
+    pub fn finish(self, label: String, world: &WorldState) -> Edit {
+        let changes: Vec<_> = self.before.into_iter()
+            .map(|b| { let a = world.snapshot_cell(b.pos); (b, a) })
+            .filter(|(b, a)| b != a)
+            .collect();
+        // Порядок потрібен, лише коли зачеплено сутності (навіть змінені на місці)
+        let touches_entities = changes.iter().any(|(b, a)| !b.entities.is_empty() || !a.entities.is_empty());
+        let mut edit = Edit::cells(label, changes);
+        if touches_entities {
+            edit.order = Some((self.order, world.entity_order()));
+        }
+        edit
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct EditHistory {
+    undo: VecDeque<Edit>,
+    redo: Vec<Edit>,
+    stored_cells: usize,
+}
+
+impl EditHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn can_undo(&self) -> bool { !self.undo.is_empty() }
+    pub fn can_redo(&self) -> bool { !self.redo.is_empty() }
+
+    /// Записує нову операцію; гілка redo після цього втрачається.
+    pub fn push(&mut self, edit: Edit) {
+        if edit.is_empty() { return; }
+
+        for old in self.redo.drain(..) {
+            self.stored_cells -= old.cost();
+        }
+        self.stored_cells += edit.cost();
+        self.undo.push_back(edit);
+
+        while self.undo.len() > HISTORY_LIMIT || (self.stored_cells > CELL_BUDGET && self.undo.len() > 1) {
+            if let Some(old) = self.undo.pop_front() {
+                self.stored_cells -= old.cost();
+            }
+        }
+    }
+
+    /// Бере останню операцію для відкату (вона переходить у redo).
+    pub fn undo(&mut self) -> Option<&Edit> {
+        let edit = self.undo.pop_back()?;
+        self.redo.push(edit);
+        self.redo.last()
+    }
+
+    pub fn redo(&mut self) -> Option<&Edit> {
+        let edit = self.redo.pop()?;
+        self.undo.push_back(edit);
+        self.undo.back()
+    }
+}
@@ -1,5 +1,11 @@
+use crate::specials::entity::Entity;
+use crate::state::actions::FormKey;
+use crate::state::form::{FieldKind, FieldValue, Form};
 use super::entity_spec::EntitySpec;
 
+/// Максимальна довжина імені юніта.
+const NAME_MAX_LEN: usize = 16;
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum InputTarget {
     None,
@@ -7,68 +13,154 @@ pub enum InputTarget {
     Hp,
     Energy,
     Damage,
+    Ai,
+    Range,
+    Name,
+    Symbol,
 }
 
+/// Y першого поля форми в бічному меню редактора.
+pub const FIELD_ROW: i32 = 18;
+
+/// Порядок полів у меню (з `FIELD_ROW` вниз); той самий для спеку і для юніта.
+pub const FIELD_ORDER: [InputTarget; 8] = [
+    InputTarget::Team,
+    InputTarget::Hp,
+    InputTarget::Energy,
+    InputTarget::Damage,
+    InputTarget::Ai,
+    InputTarget::Range,
+    InputTarget::Name,
+    InputTarget::Symbol,
+];
+
+const TEAM: FieldKind = FieldKind::Number { min: 1, max: 99 };
+const HP: FieldKind = FieldKind::Number { min: 1, max: 9999 };
+const STAT: FieldKind = FieldKind::Number { min: 0, max: 999 };
+const RANGE: FieldKind = FieldKind::Number { min: 1, max: 50 };
+
+/// Форма редагування спеку нових юнітів або вибраного юніта.
+#[derive(Default)]
 pub struct EditorInput {
-    pub input_target: InputTarget,
-    pub input_buffer: String,
+    form: Option<Form<InputTarget>>,
 }
 
 impl EditorInput {
     pub fn new() -> Self {
-        EditorInput {
-            input_target: InputTarget::None,
-            input_buffer: String::new(),
-        }
+        Self::default()
     }
 
-    pub fn cancel(&mut self) {
-        self.input_target = InputTarget::None;
-        self.input_buffer.clear();
+    pub fn form(&self) -> Option<&Form<InputTarget>> {
+        self.form.as_ref()
     }
 
-    pub fn handle_typing(&mut self, c: char) {
-        if c.is_digit(10) {
-            self.input_buffer.push(c);
-        }
+    /// Поле у фокусі або `None`, якщо форма закрита.
+    pub fn target(&self) -> InputTarget {
+        self.form.as_ref()
+            .and_then(|f| f.focused())
+            .map_or(InputTarget::None, |f| f.key())
     }
-    
-    pub fn handle_backspace(&mut self) {
-        self.input_buffer.pop();
+
+    pub fn open_spec(&mut self, spec: &EntitySpec) {
+        self.form = Some(Form::new()
+            .with_field(InputTarget::Team, "Team", TEAM, FieldValue::Number(spec.team))
+            .with_field(InputTarget::Hp, "HP ", HP, FieldValue::Number(spec.hp))
+            .with_field(InputTarget::Energy, "Eng", STAT, FieldValue::Number(spec.energy))
+            .with_field(InputTarget::Damage, "Dmg", STAT, FieldValue::Number(spec.damage))
+            .with_field(InputTarget::Ai, "AI ", FieldKind::Bool, FieldValue::Bool(spec.is_ai))
+            .with_field(InputTarget::Range, "Rng", RANGE, FieldValue::Number(spec.range))
+            .with_field(InputTarget::Name, "Name", FieldKind::Text { max_len: NAME_MAX_LEN }, FieldValue::Text(spec.name().to_string()))
+            .with_field(InputTarget::Symbol, "Sym", FieldKind::Char, FieldValue::Char(spec.symbol)));
     }
 
-    pub fn confirm(&mut self, spec: &mut EntitySpec) -> Option<String> {
-        if self.input_buffer.is_empty() {
-            self.cancel();
-            return Some("Edit Cancelled (Empty)".to_string());
+    pub fn open_entity(&mut self, entity: &Entity) {
+        self.form = Some(Form::new()
+            .with_field(InputTarget::Team, "Team", TEAM, FieldValue::Number(entity.team()))
+            .with_field(InputTarget::Hp, "HP ", HP, FieldValue::Number(entity.max_health()))
+            .with_field(InputTarget::Energy, "Eng", STAT, FieldValue::Number(entity.max_energy()))
+            .with_field(InputTarget::Damage, "Dmg", STAT, FieldValue::Number(entity.damage()))
+            .with_field(InputTarget::Ai, "AI ", FieldKind::Bool, FieldValue::Bool(entity.is_ai()))
+            .with_field(InputTarget::Range, "Rng", RANGE, FieldValue::Number(entity.attack_range()))
+            .with_field(InputTarget::Name, "Name", FieldKind::Text { max_len: NAME_MAX_LEN }, FieldValue::Text(entity.display_name().to_string()))
+            .with_field(InputTarget::Symbol, "Sym", FieldKind::Char, FieldValue::Char(entity.symbol())));
+    }
+
+    /// Переводить фокус на поле; `false`, якщо форма закрита або поля в ній немає.
+    pub fn focus(&mut self, target: InputTarget) -> bool {
+        self.form.as_mut().is_some_and(|f| f.focus(target))
+    }
+
+    pub fn handle_key(&mut self, key: FormKey) {
+        if let Some(form) = self.form.as_mut() {
+            form.handle_key(key);
         }
+    }
 
-        let result = if let Ok(val) = self.input_buffer.parse::<u32>() {
-            match self.input_target {
-                InputTarget::Team => {
+    pub fn cancel(&mut self) {
+        self.form = None;
+    }
+
+    /// Застосовує змінені поля до шаблону нових юнітів.
+    /// `Err` — форма лишається відкритою з підсвіченою помилкою.
+    pub fn confirm(&mut self, spec: &mut EntitySpec) -> Result<String, String> {
+        let values = self.take_values()?;
+        let mut changed = Vec::new();
+
+        for (target, value) in values {
+            match (target, value) {
+                (InputTarget::Team, FieldValue::Number(val)) => {
                     spec.team = val;
-                    spec.update_symbol(); 
-                    format!("Set Team to {} ({})", val, spec.symbol)
-                },
-                InputTarget::Hp => {
-                    spec.hp = val;
-                    format!("Set HP to {}", val)
-                },
-                InputTarget::Energy => {
-                    spec.energy = val;
-                    format!("Set Energy to {}", val)
-                },
-                InputTarget::Damage => {
-                    spec.damage = val;
-                    format!("Set DMG to {}", val)
-                },
-                InputTarget::None => return None,
+                    spec.update_symbol();
+                }
+                (InputTarget::Hp, FieldValue::Number(val)) => spec.hp = val,
+                (InputTarget::Energy, FieldValue::Number(val)) => spec.energy = val,
+                (InputTarget::Damage, FieldValue::Number(val)) => spec.damage = val,
+                (InputTarget::Ai, FieldValue::Bool(val)) => spec.is_ai = val,
+                (InputTarget::Range, FieldValue::Number(val)) => spec.range = val,
+                (InputTarget::Name, FieldValue::Text(s)) => spec.name = Some(s),
+                (InputTarget::Symbol, FieldValue::Char(c)) => spec.symbol = c,
+                _ => continue,
+            }
+            changed.push(format!("{:?}", target));
+        }
+        Ok(summary("Template", &changed))
+    }
+
+    /// Застосовує змінені поля до вже розміщеного юніта.
+    pub fn confirm_entity(&mut self, entity: &mut Entity) -> Result<String, String> {
+        let values = self.take_values()?;
+        let mut changed = Vec::new();
+
+        for (target, value) in values {
+            match (target, value) {
+                (InputTarget::Team, FieldValue::Number(val)) => entity.set_team(val),
+                (InputTarget::Hp, FieldValue::Number(val)) => entity.set_max_health(val),
+                (InputTarget::Energy, FieldValue::Number(val)) => entity.set_max_energy(val),
+                (InputTarget::Damage, FieldValue::Number(val)) => entity.set_damage(val),
+                (InputTarget::Ai, FieldValue::Bool(val)) => entity.set_ai(val),
+                (InputTarget::Range, FieldValue::Number(val)) => entity.set_attack_range(val),
+                (InputTarget::Name, FieldValue::Text(s)) => entity.set_name(s),
+                (InputTarget::Symbol, FieldValue::Char(c)) => entity.set_symbol(c),
+                _ => continue,
             }
-        } else {
-            "Invalid Number!".to_string()
-        };
-        
+            changed.push(format!("{:?}", target));
+        }
+        Ok(summary(&format!("#{}", entity.id()), &changed))
+    }
+
+    /// Перевіряє форму; при успіху закриває її і віддає змінені значення.
+    fn take_values(&mut self) -> Result<Vec<(InputTarget, FieldValue)>, String> {
+        let form = self.form.as_mut().ok_or_else(|| "No edit in progress".to_string())?;
+        let values = form.modified_values()?;
         self.cancel();
-        Some(result)
+        Ok(values)
     }
-}
\ No newline at end of file
+}
+
+fn summary(owner: &str, changed: &[String]) -> String {
+    if changed.is_empty() {
+        format!("{}: no changes", owner)
+    } else {
+        format!("{}: set {}", owner, changed.join(", "))
+    }
+}
@@ -0,0 +1,66 @@
+// src/state/modes/editor/map_dialog.rs
+
+use crate::state::form::{FieldKind, FieldValue, Form};
+
+/// Межі розміру карти, які дозволяє діалог.
+pub const MIN_MAP_SIZE: u32 = 5;
+pub const MAX_MAP_SIZE: u32 = 500;
+
+const SIZE: FieldKind = FieldKind::Number { min: MIN_MAP_SIZE, max: MAX_MAP_SIZE };
+
+/// Що зробить діалог після підтвердження.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MapDialogKind {
+    /// Порожня карта нового розміру.
+    New,
+    /// Поточна карта обрізається або доповнюється.
+    Resize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MapField {
+    Width,
+    Height,
+}
+
+/// Модальне вікно з розмірами карти.
+#[derive(Debug, Clone)]
+pub struct MapDialog {
+    kind: MapDialogKind,
+    form: Form<MapField>,
+}
+
+impl MapDialog {
+    pub fn new(kind: MapDialogKind, width: i32, height: i32) -> Self {
+        let form = Form::new()
+            .with_field(MapField::Width, "Width ", SIZE, FieldValue::Number(width.max(0) as u32))
+            .with_field(MapField::Height, "Height", SIZE, FieldValue::Number(height.max(0) as u32));
+        MapDialog { kind, form }
+    }
+
+    pub fn kind(&self) -> MapDialogKind { self.kind }
+    pub fn form(&self) -> &Form<MapField> { &self.form }
+    pub fn form_mut(&mut self) -> &mut Form<MapField> { &mut self.form }
+
+    pub fn title(&self) -> &'static str {
+        match self.kind {
+            MapDialogKind::New => " New Map ",
+            MapDialogKind::Resize => " Resize Map ",
+        }
+    }
+
+    /// Перевірені (ширина, висота) або текст помилки.
+    pub fn size(&mut self) -> Result<(i32, i32), String> {
+        let mut width = 0;
+        let mut height = 0;
+        for (field, value) in self.form.validate()? {
+            if let FieldValue::Number(n) = value {
+                match field {
+                    MapField::Width => width = n as i32,
+                    MapField::Height => height = n as i32,
+                }
+            }
+        }
+        Ok((width, height))
+    }
+}
@@ -3,16 +3,21 @@ use ratatui::style::{Style, Color, Modifier};
 
 use crate::tui::menu::{MenuState, MenuItem};
 use crate::tui::utils::get_team_color;
+use crate::tui::form::field_line;
 use crate::state::actions::BuildTool;
 
 use super::editor_mode::EditorMode; 
-use super::input::InputTarget; 
+use super::input::FIELD_ORDER; 
 
 impl MenuState for EditorMode {
-    fn get_title(&self) -> String { " EDITOR ".to_string() }
+    fn get_title(&self) -> String { format!(" EDITOR: {} ", self.save_path()) }
 
     fn get_tools(&self) -> Vec<MenuItem> {
         let t = self.current_tool(); 
+        let powerup_label = format!("Powerup: {}", self.powerup_type().name());
+        let brush_label = format!("Brush: {}", self.brush().name());
+        let map = &self.world().map;
+        let size_label = format!("Resize ({}x{})", map.width(), map.height());
         
         vec![
             MenuItem::spacer(), // Y=1
@@ -20,54 +25,86 @@ impl MenuState for EditorMode {
             MenuItem::new("W", "Wall", t == BuildTool::Wall), // Y=3
             MenuItem::new("F", "Floor", t == BuildTool::Floor), // Y=4
             MenuItem::new("U", "Unit", t == BuildTool::Unit), // Y=5
-            MenuItem::spacer(), // Y=6
-            MenuItem::colored("S", "Save Map", Color::Blue), // Y=7
-            MenuItem::spacer(), // Y=8
-            MenuItem::colored("Q", "Quit", Color::Red), // Y=9
+            MenuItem::new("P", &powerup_label, t == BuildTool::Powerup), // Y=6
+            MenuItem::new("I", "Select", t == BuildTool::Select), // Y=7
+            MenuItem::colored("B", &brush_label, Color::Gray), // Y=8
+            MenuItem::spacer(), // Y=9
+            MenuItem::colored("S", "Save Map", Color::Blue), // Y=10
+            MenuItem::colored("V", "Save As...", Color::Blue), // Y=11
+            MenuItem::colored("M", "New Map", Color::Blue), // Y=12
+            MenuItem::colored("X", &size_label, Color::Blue), // Y=13
+            MenuItem::spacer(), // Y=14
+            MenuItem::colored("Q", "Quit", Color::Red), // Y=15
         ]
     }
 
     fn get_info_section(&self) -> Vec<Line<'_>> {
         let mut lines = Vec::new();
-        // Y=11
-        lines.push(Line::from(Span::styled("--- ENTITY CFG ---", Style::default().add_modifier(Modifier::BOLD))));
+        let unit = self.selected_unit();
+        let field = |label: &str, val: String| Line::from(format!("{}: [{}]", label, val));
 
-        let spec = self.entity_spec();
-        let current_target = self.input_target();
-        let buffer = self.input_buffer();
-
-        // Closure з явним лайфтаймом <'_>
-        let format_input = |label: &str, is_target: bool, val: String| -> Line<'_> {
-            if is_target {
-                let txt = format!("{}: [ {}_ ]", label, buffer);
-                Line::from(Span::styled(txt, Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)))
-            } else {
-                Line::from(format!("{}: [{}]", label, val))
-            }
+        // Y=17
+        let header = match unit {
+            Some(u) => format!("--- UNIT #{} ({}, {}) ---", u.id(), u.position().x(), u.position().y()),
+            None => "--- ENTITY CFG ---".to_string(),
         };
+        lines.push(Line::from(Span::styled(header, Style::default().add_modifier(Modifier::BOLD))));
+
+        // Y=18..25 — відкрита форма малює поля сама (той самий порядок, що й FIELD_ORDER)
+        if let Some(form) = self.input_form() {
+            for target in FIELD_ORDER {
+                lines.extend(field_line(form, target));
+            }
+            return lines;
+        }
+
+        // Рядки мають ті самі Y, що й для спеку, тож клік по меню редагує те саме поле
+        if let Some(unit) = unit {
+            // Y=18
+            let txt = format!("Team: [{}]", unit.team());
+            lines.push(Line::from(Span::styled(txt, Style::default().fg(get_team_color(unit.team())))));
 
-        // Y=12
-        if current_target == InputTarget::Team {
-             lines.push(format_input("Team", true, "".into()));
-        } else {
-             let color = get_team_color(spec.team);
-             let txt = format!("Team: [{}] ({})", spec.team, spec.symbol);
-             lines.push(Line::from(Span::styled(txt, Style::default().fg(color))));
+            // Y=19..21
+            lines.push(field("HP ", format!("{}/{}", unit.health(), unit.max_health())));
+            lines.push(field("Eng", format!("{}/{}", unit.energy(), unit.max_energy())));
+            lines.push(field("Dmg", unit.damage().to_string()));
+
+            // Y=22
+            let ai_str = if unit.is_ai() { "AI:   [ON]" } else { "AI:   [OFF]" };
+            lines.push(Line::from(Span::styled(ai_str, Style::default().fg(Color::Yellow))));
+
+            // Y=23..25
+            lines.push(field("Rng", unit.attack_range().to_string()));
+            lines.push(field("Name", unit.display_name().to_string()));
+            lines.push(field("Sym", unit.symbol().to_string()));
+            return lines;
         }
 
-        // Y=13, 14, 15
-        lines.push(format_input("HP ", current_target == InputTarget::Hp, spec.hp.to_string()));
-        lines.push(format_input("Eng", current_target == InputTarget::Energy, spec.energy.to_string()));
-        lines.push(format_input("Dmg", current_target == InputTarget::Damage, spec.damage.to_string()));
+        let spec = self.entity_spec();
+
+        // Y=18
+        let color = get_team_color(spec.team);
+        let txt = format!("Team: [{}] ({})", spec.team, spec.symbol);
+        lines.push(Line::from(Span::styled(txt, Style::default().fg(color))));
+
+        // Y=19, 20, 21
+        lines.push(field("HP ", spec.hp.to_string()));
+        lines.push(field("Eng", spec.energy.to_string()));
+        lines.push(field("Dmg", spec.damage.to_string()));
 
-        // Y=16
+        // Y=22
         let ai_str = if spec.is_ai { "AI:   [ON]" } else { "AI:   [OFF]" };
         lines.push(Line::from(Span::styled(ai_str, Style::default().fg(Color::Yellow))));
 
+        // Y=23..25
+        lines.push(field("Rng", spec.range.to_string()));
+        lines.push(field("Name", spec.name().to_string()));
+        lines.push(field("Sym", spec.symbol.to_string()));
+
         lines
     }
 
-    fn get_logs(&self) -> String {
-        self.debug_message().to_string()
+    fn get_logs(&self) -> Vec<Line<'_>> {
+        vec![Line::from(self.debug_message())]
     }
 }
\ No newline at end of file
@@ -2,5 +2,9 @@ pub mod editor_mode;
 pub mod input;
 pub mod entity_spec;
 pub mod menu;
+pub mod history;
+pub mod brush;
+pub mod map_dialog;
+pub mod save_dialog;
 
 pub use editor_mode::EditorMode;
\ No newline at end of file
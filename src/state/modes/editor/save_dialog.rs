@@ -0,0 +1,66 @@
+// src/state/modes/editor/save_dialog.rs
+
+use std::path::Path;
+
+use crate::state::map_files::{file_name, map_path};
+use crate::state::form::{FieldKind, FieldValue, Form};
+
+const NAME_MAX_LEN: usize = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveField {
+    Name,
+}
+
+/// Діалог "Save As": ім'я файлу в теці карт і підтвердження перезапису.
+#[derive(Debug, Clone)]
+pub struct SaveAsDialog {
+    form: Form<SaveField>,
+    /// Шлях, який уже існує і чекає на повторний Enter.
+    overwrite: Option<String>,
+}
+
+impl SaveAsDialog {
+    /// `current` — поточний шлях збереження (з нього береться ім'я за замовчуванням).
+    pub fn new(current: &str) -> Self {
+        let name = Path::new(current)
+            .file_stem()
+            .map_or_else(String::new, |s| s.to_string_lossy().into_owned());
+        let form = Form::new()
+            .with_field(SaveField::Name, "Name", FieldKind::Text { max_len: NAME_MAX_LEN }, FieldValue::Text(name));
+        SaveAsDialog { form, overwrite: None }
+    }
+
+    pub fn form(&self) -> &Form<SaveField> { &self.form }
+
+    pub fn title(&self) -> &'static str { " Save As " }
+
+    /// Підказка над кнопками: питання про перезапис, якщо воно активне.
+    pub fn notice(&self) -> Option<String> {
+        self.overwrite.as_ref().map(|path| format!("'{}' exists. Enter: overwrite", path))
+    }
+
+    pub fn form_mut(&mut self) -> &mut Form<SaveField> {
+        // Будь-яка зміна імені скасовує питання про перезапис
+        self.overwrite = None;
+        &mut self.form
+    }
+
+    /// Шлях для збереження, коли все підтверджено. `Ok(None)` — файл уже існує
+    /// і діалог тепер питає про перезапис; `current` перезаписується без питань.
+    pub fn target(&mut self, current: &str) -> Result<Option<String>, String> {
+        let values = self.form.validate()?;
+        let name = match values.first() {
+            Some((_, FieldValue::Text(name))) => file_name(name)?,
+            _ => return Err("File name can't be empty".to_string()),
+        };
+        let path = map_path(&name);
+
+        let confirmed = self.overwrite.as_deref() == Some(path.as_str());
+        if path != current && !confirmed && Path::new(&path).exists() {
+            self.overwrite = Some(path);
+            return Ok(None);
+        }
+        Ok(Some(path))
+    }
+}
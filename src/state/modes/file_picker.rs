@@ -0,0 +1,70 @@
+// src/state/modes/file_picker.rs
+
+use std::path::PathBuf;
+
+use crate::state::map_files::{list_maps, map_path, maps_dir};
+
+/// Для чого відкривається вибрана карта.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PickerPurpose {
+    Play,
+    Edit,
+}
+
+/// Список файлів карт у теці карт зі стрілкою вибору.
+#[derive(Debug, Clone)]
+pub struct FilePicker {
+    purpose: PickerPurpose,
+    dir: PathBuf,
+    files: Vec<String>,
+    selected: usize,
+    /// Остання помилка (читання теки чи завантаження файлу) — показується у вікні.
+    error: Option<String>,
+}
+
+impl FilePicker {
+    pub fn new(purpose: PickerPurpose) -> Self {
+        let dir = maps_dir();
+        let (files, error) = match list_maps(&dir) {
+            Ok(files) if files.is_empty() => (files, Some("No map files here".to_string())),
+            Ok(files) => (files, None),
+            Err(e) => (Vec::new(), Some(format!("Can't read '{}': {}", dir.display(), e))),
+        };
+        FilePicker { purpose, dir, files, selected: 0, error }
+    }
+
+    pub fn purpose(&self) -> PickerPurpose { self.purpose }
+    pub fn dir(&self) -> &PathBuf { &self.dir }
+    pub fn files(&self) -> &[String] { &self.files }
+    pub fn selected(&self) -> usize { self.selected }
+    pub fn error(&self) -> Option<&str> { self.error.as_deref() }
+
+    pub fn set_error(&mut self, error: String) {
+        self.error = Some(error);
+    }
+
+    /// Перший видимий рядок, щоб вибраний файл завжди потрапляв у вікно з `visible` рядків.
+    pub fn scroll_offset(&self, visible: usize) -> usize {
+        self.selected.saturating_sub(visible.saturating_sub(1))
+    }
+
+    pub fn move_selection(&mut self, delta: i32) {
+        if self.files.is_empty() { return; }
+        let last = self.files.len() as i32 - 1;
+        self.selected = (self.selected as i32 + delta).clamp(0, last) as usize;
+    }
+
+    pub fn select(&mut self, index: usize) -> bool {
+        if index < self.files.len() {
+            self.selected = index;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Шлях до вибраного файлу (як його передати у `WorldState::load`).
+    pub fn selected_path(&self) -> Option<String> {
+        self.files.get(self.selected).map(|name| map_path(name))
+    }
+}
@@ -1,92 +1,114 @@
-use super::engine::{GameEngine, ActionResult};
-use crate::specials::entity::{EntityID, MOVE_COST}; 
-use crate::map::position::MapPosition;
+use super::engine::GameEngine;
+use super::command::EngineCommand;
+use crate::specials::entity::EntityID;
+use crate::specials::skill::{SkillEffect, TargetShape};
 use super::pathfinding::Pathfinding;
 
-enum AiDecision {
-    Attack(EntityID),
-    Move(MapPosition),
-    Wait,
-}
-
 pub struct AiSystem;
 
 impl AiSystem {
-    pub fn perform_turn(engine: &mut GameEngine, team_id: u32) -> Vec<String> {
-        let mut turn_logs = Vec::new();
-
+    /// Ходить усіма AI-юнітами команди. Що сталося — дивись у черзі подій рушія.
+    pub fn perform_turn(engine: &mut GameEngine, team_id: u32) {
         let ai_ids: Vec<EntityID> = engine.world().entities.iter()
             .filter(|e| e.team() == team_id && e.is_ai() && !e.is_dead())
             .map(|e| e.id())
             .collect();
 
         for id in ai_ids {
-            let unit_logs = Self::process_entity_loop(engine, id);
-            turn_logs.extend(unit_logs);
+            Self::process_entity_loop(engine, id);
         }
-        
-        turn_logs
     }
 
-    fn process_entity_loop(engine: &mut GameEngine, entity_id: EntityID) -> Vec<String> {
-        let mut logs = Vec::new();
-
+    fn process_entity_loop(engine: &mut GameEngine, entity_id: EntityID) {
         for _ in 0..20 {
             let decision = Self::decide_next_action(engine, entity_id);
 
             match decision {
-                AiDecision::Attack(target_id) => {
-                    logs.push(format!("AI {} attacks {}!", entity_id, target_id));
-                    engine.attack_entity(entity_id, target_id);
+                EngineCommand::Attack { .. } => {
+                    let _ = engine.apply(decision);
                     break;
                 },
-                AiDecision::Move(pos) => {
-                    if let ActionResult::Fail(_) = engine.move_entity(entity_id, pos) {
+                EngineCommand::Wait { .. } => {
+                    break; 
+                },
+                _ => {
+                    if engine.apply(decision).is_err() {
                         break; 
                     }
-                },
-                AiDecision::Wait => {
-                    break; 
                 }
             }
         }
-        logs
     }
 
-    fn decide_next_action(engine: &GameEngine, entity_id: EntityID) -> AiDecision {
+    fn decide_next_action(engine: &GameEngine, entity_id: EntityID) -> EngineCommand {
+        let wait = EngineCommand::Wait { entity: entity_id };
         let state = engine.world();
         
         let me = match state.get_entity(entity_id) {
             Some(e) if !e.is_dead() => e,
-            _ => return AiDecision::Wait,
+            _ => return wait,
         };
 
-        if !me.can_act(MOVE_COST) {
-            return AiDecision::Wait;
+        let legal = engine.legal_actions(entity_id);
+        if legal.is_empty() {
+            return wait;
         }
 
         let my_pos = me.position();
         let my_range = me.attack_range();
 
+        // 1. Лікуємо союзника, якщо в нього менше половини здоров'я.
+        for &(slot, pos) in &legal.skills {
+            if engine.skill_spec(me.skills()[slot].kind()).effect != SkillEffect::Heal { continue; }
+            let wounded = state.get_entity_id_at(pos)
+                .and_then(|id| state.get_entity(id))
+                .is_some_and(|e| e.health() * 2 < e.max_health());
+            if wounded {
+                return EngineCommand::UseSkill { entity: entity_id, skill: slot, target: pos };
+            }
+        }
+
         let target = state.entities.iter()
             .filter(|e| e.team() != me.team() && !e.is_dead())
             .map(|e| (e.id(), e.position(), e.position().manhattan_distance(&my_pos)))
             .min_by_key(|(_, _, dist)| *dist);
 
         if let Some((target_id, target_pos, dist)) = target {
+            // 2. Бойові вміння по найближчому ворогу (вибух — лише без своїх у радіусі).
+            for &(slot, pos) in &legal.skills {
+                if pos != target_pos { continue; }
+                let spec = engine.skill_spec(me.skills()[slot].kind());
+                if spec.effect != SkillEffect::Damage { continue; }
+                let usable = match spec.shape {
+                    TargetShape::Enemy => true,
+                    TargetShape::Area { radius } => !state.entities.iter().any(|e| {
+                        e.id() != entity_id && e.team() == me.team() && !e.is_dead()
+                            && e.position().manhattan_distance(&pos) <= radius
+                    }),
+                    _ => false,
+                };
+                if usable {
+                    return EngineCommand::UseSkill { entity: entity_id, skill: slot, target: pos };
+                }
+            }
+
             if dist <= my_range {
-                return AiDecision::Attack(target_id);
-            } 
+                if legal.can_attack(target_id) {
+                    return EngineCommand::Attack { attacker: entity_id, target: target_id };
+                }
+                // Ціль поруч, але на атаку не вистачає енергії — чекаємо.
+                return wait;
+            }
             
             if let Some(path) = Pathfinding::find_path(&state.map, my_pos, target_pos) {
                 if let Some(&next_step) = path.first() {
-                    if state.map.is_standable(next_step) {
-                        return AiDecision::Move(next_step);
+                    if legal.can_move_to(next_step) {
+                        return EngineCommand::Move { entity: entity_id, to: next_step };
                     }
                 }
             }
         }
 
-        AiDecision::Wait
+        wait
     }
 }
\ No newline at end of file
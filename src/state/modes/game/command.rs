@@ -0,0 +1,18 @@
+// src/state/modes/game/command.rs
+
+use serde::{Serialize, Deserialize};
+use crate::specials::entity::EntityID;
+use crate::map::position::MapPosition;
+
+/// Єдина мова команд для рушія: UI, AI, RL-агенти, реплеї та мережа
+/// передають у `GameEngine::apply` саме її.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EngineCommand {
+    Move { entity: EntityID, to: MapPosition },
+    Attack { attacker: EntityID, target: EntityID },
+    UseSkill { entity: EntityID, skill: usize, target: MapPosition },
+    UsePowerup { entity: EntityID, target: MapPosition },
+    EndTurn,
+    /// Юніт свідомо пропускає дію (хід команди триває).
+    Wait { entity: EntityID },
+}
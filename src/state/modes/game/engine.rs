@@ -1,6 +1,18 @@
+use std::collections::HashSet;
+
 use crate::state::WorldState;
 use crate::specials::entity::{EntityID, MOVE_COST, ATTACK_COST};
+use crate::specials::powerup::{PowerupType, HEAL_AMOUNT, SPEED_BOOST_ENERGY};
+use crate::specials::skill::{SkillKind, SkillSpec, SkillTable, SkillEffect, TargetShape};
+use crate::specials::status::{StatusEffect, StatusKind};
 use crate::map::position::MapPosition;
+use crate::map::tile::TileType;
+
+use super::command::EngineCommand;
+use super::events::GameEvent;
+use super::error::EngineError;
+use super::pathfinding::Pathfinding;
+use super::victory::{WinCondition, GameOutcome, alive_teams};
 
 #[derive(Debug, Clone)]
 pub enum TurnResult {
@@ -10,24 +22,124 @@ pub enum TurnResult {
 
 #[derive(Debug, Clone)]
 pub enum ActionResult {
-    SuccessMove,
+    /// `path` — пройдені клітинки без стартової (остання = ціль).
+    SuccessMove { path: Vec<MapPosition>, cost: u32 },
     SuccessAttack { damage: u32, target_died: bool },
-    Fail(String),
+    SuccessWallBreak { pos: MapPosition },
+    /// `affected` — юніти, яких зачепило вміння.
+    SuccessSkill { skill: SkillKind, affected: Vec<EntityID> },
+}
+
+/// Результат `GameEngine::legal_actions`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LegalActions {
+    pub moves: Vec<MapPosition>,
+    pub attacks: Vec<EntityID>,
+    /// Пари (слот вміння, цільова клітинка).
+    pub skills: Vec<(usize, MapPosition)>,
+}
+
+impl LegalActions {
+    pub fn is_empty(&self) -> bool {
+        self.moves.is_empty() && self.attacks.is_empty() && self.skills.is_empty()
+    }
+
+    pub fn can_move_to(&self, pos: MapPosition) -> bool {
+        self.moves.contains(&pos)
+    }
+
+    pub fn can_attack(&self, target: EntityID) -> bool {
+        self.attacks.contains(&target)
+    }
+
+    pub fn can_use_skill(&self, slot: usize, target: MapPosition) -> bool {
+        self.skills.contains(&(slot, target))
+    }
 }
 
 pub struct GameEngine {
     world: WorldState, // Приватне поле
+    events: Vec<GameEvent>, // Черга подій, яку забирають UI, реплеї, RL тощо
+
+    /// Параметри вмінь (дані, а не код).
+    skills: SkillTable,
+
+    // Правила завершення гри
+    win_conditions: Vec<WinCondition>,
+    outcome: Option<GameOutcome>,
+    turn: u32,
+    started_teams: usize,
 }
 
 impl GameEngine {
+    /// Умови перемоги беруться з карти (`WorldState::win_conditions`).
     pub fn new(world: WorldState) -> Self {
-        Self { world }
+        let started_teams = alive_teams(&world).len();
+        let win_conditions = if world.win_conditions.is_empty() {
+            vec![WinCondition::LastTeamStanding]
+        } else {
+            world.win_conditions.clone()
+        };
+        Self {
+            world,
+            events: Vec::new(),
+            skills: SkillTable::default(),
+            win_conditions,
+            outcome: None,
+            turn: 1,
+            started_teams,
+        }
     }
 
     pub fn world(&self) -> &WorldState {
         &self.world
     }
 
+    /// Події, що ще не були забрані через `drain_events`.
+    pub fn pending_events(&self) -> &[GameEvent] {
+        &self.events
+    }
+
+    /// Забирає всі накопичені події (черга очищується).
+    pub fn drain_events(&mut self) -> Vec<GameEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// Номер поточного раунду (збільшується, коли хід повертається до першої команди).
+    pub fn turn(&self) -> u32 {
+        self.turn
+    }
+
+    pub fn outcome(&self) -> Option<&GameOutcome> {
+        self.outcome.as_ref()
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.outcome.is_some()
+    }
+
+    pub fn skill_table(&self) -> &SkillTable {
+        &self.skills
+    }
+
+    pub fn skill_spec(&self, kind: SkillKind) -> &SkillSpec {
+        self.skills.spec(kind)
+    }
+
+    /// Замінює таблицю вмінь (наприклад, завантажену через `SkillTable::load`).
+    pub fn set_skill_table(&mut self, skills: SkillTable) {
+        self.skills = skills;
+    }
+
+    pub fn win_conditions(&self) -> &[WinCondition] {
+        &self.win_conditions
+    }
+
+    /// Замінює умови перемоги (перевіряються по порядку, перша що спрацювала — вирішує).
+    pub fn set_win_conditions(&mut self, conditions: Vec<WinCondition>) {
+        self.win_conditions = conditions;
+    }
+
     pub fn world_mut(&mut self) -> &mut WorldState {
         &mut self.world
     }
@@ -36,116 +148,455 @@ impl GameEngine {
     //                            PUBLIC API
     // =========================================================================
 
-    pub fn move_entity(&mut self, id: EntityID, target_pos: MapPosition) -> ActionResult {
-        if let Err(e) = self.validate_actor(id, MOVE_COST) { return e; }
-
-        if !self.world.map.is_standable(&target_pos) {
-            return ActionResult::Fail("Position blocked".to_string());
+    /// Єдина точка входу для команд. Повертає події, які спричинила команда
+    /// (вони ж лишаються в черзі для `drain_events`).
+    pub fn apply(&mut self, cmd: EngineCommand) -> Result<Vec<GameEvent>, EngineError> {
+        if let Some(outcome) = &self.outcome {
+            return Err(EngineError::GameOver { winner: outcome.winner });
         }
 
-        let old_pos = self.world.get_entity(id).unwrap().position();
-        let cost = old_pos.manhattan_distance(&target_pos) * MOVE_COST;
+        let first_event = self.events.len();
 
-        if let Some(e) = self.world.get_entity(id) {
-            if !e.can_act(cost) { return ActionResult::Fail("Not enough energy for distance".to_string()); }
+        match cmd {
+            EngineCommand::Move { entity, to } => { self.move_entity(entity, to)?; }
+            EngineCommand::Attack { attacker, target } => { self.attack_entity(attacker, target)?; }
+            EngineCommand::UseSkill { entity, skill, target } => { self.use_skill(entity, skill, target)?; }
+            EngineCommand::UsePowerup { entity, target } => { self.use_powerup(entity, target)?; }
+            EngineCommand::EndTurn => { self.end_turn(); }
+            EngineCommand::Wait { entity } => self.validate_actor(entity, 0)?,
         }
 
+        self.evaluate_outcome(first_event);
+
+        Ok(self.events[first_event..].to_vec())
+    }
+
+    /// Рухає юніта найкоротшим шляхом прохідними вільними клітинками.
+    /// Енергія списується за фактичну довжину шляху.
+    pub fn move_entity(&mut self, id: EntityID, target_pos: MapPosition) -> Result<ActionResult, EngineError> {
+        let (path, cost) = self.plan_move(id, target_pos)?;
+
         self.apply_energy_cost(id, cost);
-        
-        if let Some(e) = self.world.get_entity_mut(id) {
-            e.set_position(target_pos);
-        }
+        self.follow_path(id, &path);
+
+        Ok(ActionResult::SuccessMove { path, cost })
+    }
+
+    pub fn attack_entity(&mut self, attacker_id: EntityID, target_id: EntityID) -> Result<ActionResult, EngineError> {
+        self.check_attack(attacker_id, target_id)?;
 
-        self.update_map_placement(old_pos, target_pos, id);
+        let damage = self.world.get_entity(attacker_id).unwrap().damage();
 
-        ActionResult::SuccessMove
+        self.apply_energy_cost(attacker_id, ATTACK_COST);
+        let target_died = self.deal_damage(attacker_id, target_id, damage);
+
+        Ok(ActionResult::SuccessAttack { damage, target_died })
     }
 
-    pub fn attack_entity(&mut self, attacker_id: EntityID, target_id: EntityID) -> ActionResult {
-        if let Err(e) = self.validate_actor(attacker_id, ATTACK_COST) { return e; }
-        
-        if attacker_id == target_id {
-            return ActionResult::Fail("Cannot attack self".to_string());
+    /// Застосовує вміння зі слоту `slot` на клітинку `target`.
+    /// Що саме станеться, визначає запис у `SkillTable` (форма цілі й ефект).
+    pub fn use_skill(&mut self, id: EntityID, slot: usize, target: MapPosition) -> Result<ActionResult, EngineError> {
+        let (spec, path) = self.check_skill(id, slot, target)?;
+
+        self.apply_energy_cost(id, spec.energy_cost);
+        if let Some(e) = self.world.get_entity_mut(id) {
+            e.start_skill_cooldown(slot, spec.cooldown);
         }
+        self.events.push(GameEvent::SkillUsed { entity: id, skill: spec.kind, target });
+
+        let affected: Vec<EntityID> = match spec.shape {
+            TargetShape::Enemy | TargetShape::Ally => self.world.get_entity_id_at(target).into_iter().collect(),
+            TargetShape::Area { radius } => self.world.entities.iter()
+                .filter(|e| e.id() != id && !e.is_dead())
+                .filter(|e| e.position().manhattan_distance(&target) <= radius)
+                .map(|e| e.id())
+                .collect(),
+            TargetShape::EmptyTile => Vec::new(),
+        };
 
-        let (damage, range, attacker_pos, target_pos) = {
-            let att = self.world.get_entity(attacker_id).unwrap();
-            let tgt = match self.world.get_entity(target_id) {
-                Some(t) => t,
-                None => return ActionResult::Fail("Target lost".to_string()),
-            };
-            (att.damage(), att.attack_range(), att.position(), tgt.position())
+        match spec.effect {
+            SkillEffect::Damage => {
+                for &victim in &affected {
+                    self.deal_damage(id, victim, spec.power);
+                }
+            }
+            SkillEffect::Heal => {
+                for &ally in &affected {
+                    if let Some(e) = self.world.get_entity_mut(ally) {
+                        let before = e.health();
+                        e.heal(spec.power);
+                        let amount = e.health() - before;
+                        self.events.push(GameEvent::EntityHealed { entity: ally, amount });
+                    }
+                }
+            }
+            SkillEffect::Dash => self.follow_path(id, &path),
+        }
+
+        if let Some(effect) = spec.applies {
+            for &t in &affected {
+                self.apply_status(t, effect);
+            }
+        }
+
+        Ok(ActionResult::SuccessSkill { skill: spec.kind, affected })
+    }
+
+    /// Накладає статус-ефект на живу сутність (з урахуванням стакання).
+    pub fn apply_status(&mut self, id: EntityID, effect: StatusEffect) {
+        let e = match self.world.get_entity_mut(id) {
+            Some(e) if !e.is_dead() => e,
+            _ => return,
         };
+        e.apply_status(effect);
+        let turns = e.status(effect.kind()).map_or(0, |s| s.turns_left());
+        self.events.push(GameEvent::StatusApplied { entity: id, status: effect.kind(), turns });
+    }
+
+    /// Використовує заряд WallBreaker: руйнує стіну на сусідній клітинці.
+    pub fn use_powerup(&mut self, id: EntityID, target: MapPosition) -> Result<ActionResult, EngineError> {
+        self.validate_actor(id, 0)?;
 
-        if attacker_pos.manhattan_distance(&target_pos) > range {
-            return ActionResult::Fail("Target out of range".to_string());
+        let me = self.world.get_entity(id).unwrap();
+        if me.wall_breaker_charges() == 0 {
+            return Err(EngineError::NoPowerup);
         }
 
-        self.apply_energy_cost(attacker_id, ATTACK_COST);
+        let distance = me.position().manhattan_distance(&target);
+        if distance != 1 {
+            return Err(EngineError::OutOfRange { distance, range: 1 });
+        }
 
-        let mut target_died = false;
-        if let Some(tgt) = self.world.get_entity_mut(target_id) {
-            target_died = tgt.take_damage(damage);
+        if !self.world.map.get_tile(target).is_some_and(|t| t.is_solid()) {
+            return Err(EngineError::NotAWall(target));
         }
 
-        if target_died {
-            self.clear_map_tile(target_pos);
+        if let Some(e) = self.world.get_entity_mut(id) {
+            e.use_wall_breaker();
         }
+        if let Some(tile) = self.world.map.get_tile_mut(target) {
+            tile.transform(TileType::WalkableGeneric);
+        }
+        self.events.push(GameEvent::WallDestroyed { entity: id, pos: target });
 
-        ActionResult::SuccessAttack { damage, target_died }
+        Ok(ActionResult::SuccessWallBreak { pos: target })
     }
 
     pub fn end_turn(&mut self) -> TurnResult {
-        let mut active_teams: Vec<u32> = self.world.entities.iter()
-            .filter(|e| !e.is_dead())
-            .map(|e| e.team())
-            .collect();
-        active_teams.sort();
-        active_teams.dedup();
-
-        if active_teams.is_empty() { return TurnResult::TurnContinues; }
+        if alive_teams(&self.world).is_empty() { return TurnResult::TurnContinues; }
 
+        // Ефекти спадають наприкінці власного ходу: застанений юніт пропускає рівно стільки ходів.
         let current = self.world.current_team_turn;
-        let next_team = if let Some(pos) = active_teams.iter().position(|&t| t == current) {
-            active_teams[(pos + 1) % active_teams.len()]
-        } else {
-            active_teams[0]
-        };
-
-        self.world.current_team_turn = next_team;
-        
         for e in self.world.entities.iter_mut() {
-            if e.team() == next_team {
-                e.reduce_stun();
-                e.refill_energy();
+            if e.team() == current && !e.is_dead() {
+                for status in e.tick_statuses() {
+                    self.events.push(GameEvent::StatusExpired { entity: e.id(), status });
+                }
+            }
+        }
+
+        // Отрута на початку ходу може добити всю команду — тоді хід переходить далі.
+        loop {
+            let active_teams = alive_teams(&self.world);
+            if active_teams.is_empty() { return TurnResult::TurnContinues; }
+
+            // Наступна за номером жива команда; якщо такої немає — нове коло.
+            let previous = self.world.current_team_turn;
+            let next_team = match active_teams.iter().find(|&&t| t > previous) {
+                Some(&team) => team,
+                None => {
+                    self.turn += 1;
+                    active_teams[0]
+                }
+            };
+
+            self.world.current_team_turn = next_team;
+            self.events.push(GameEvent::TurnStarted { team: next_team });
+
+            let team_ids: Vec<EntityID> = self.world.entities.iter()
+                .filter(|e| e.team() == next_team && !e.is_dead())
+                .map(|e| e.id())
+                .collect();
+            for id in team_ids {
+                self.start_turn_effects(id);
+                if let Some(e) = self.world.get_entity_mut(id) {
+                    e.tick_cooldowns();
+                    e.refill_energy();
+                }
+            }
+
+            if alive_teams(&self.world).contains(&next_team) {
+                return TurnResult::TurnChanged(next_team);
+            }
+        }
+    }
+
+    /// Шлях і вартість ходу до `to` без виконання (ті самі перевірки, що й `move_entity`).
+    pub fn preview_move(&self, id: EntityID, to: MapPosition) -> Result<(Vec<MapPosition>, u32), EngineError> {
+        self.plan_move(id, to)
+    }
+
+    /// Клітинки, які юніти інших команд (не `team`) зможуть атакувати у свій наступний хід:
+    /// рух на повну енергію мінус вартість атаки, далі — дальність атаки.
+    /// Застанені зараз юніти пропускають і наступний хід, тому не враховуються.
+    pub fn threat_map(&self, team: u32) -> HashSet<MapPosition> {
+        let mut threat = HashSet::new();
+        let map = &self.world.map;
+
+        for e in self.world.entities.iter().filter(|e| e.team() != team && !e.is_dead() && !e.is_stunned()) {
+            let haste = e.status(StatusKind::Haste).map_or(0, |s| s.potency());
+            let energy = e.max_energy() + haste;
+            if energy < ATTACK_COST { continue; }
+
+            let steps = if e.is_rooted() { 0 } else { (energy - ATTACK_COST) / MOVE_COST.max(1) };
+            let mut origins: Vec<MapPosition> = Pathfinding::reachable(map, e.position(), steps).into_keys().collect();
+            origins.push(e.position());
+
+            for origin in origins {
+                threat.extend(origin.diamond(e.attack_range()).into_iter().filter(|p| map.in_bounds(*p)));
             }
         }
-        TurnResult::TurnChanged(next_team)
+
+        threat
     }
 
     pub fn current_team(&self) -> u32 {
         self.world.current_team_turn
     }
 
+    /// Клітинки, куди юніт може піти цього ходу (без перевірки вмінь — дешево для UI).
+    pub fn reachable_tiles(&self, id: EntityID) -> Vec<MapPosition> {
+        let me = match self.world.get_entity(id) {
+            Some(e) if self.validate_actor(id, MOVE_COST).is_ok() && !e.is_rooted() => e,
+            _ => return Vec::new(),
+        };
+        let reach = me.energy() / MOVE_COST.max(1);
+        let mut moves: Vec<MapPosition> = Pathfinding::reachable(&self.world.map, me.position(), reach)
+            .into_keys()
+            .collect();
+        moves.sort_by_key(|p| (p.y(), p.x()));
+        moves
+    }
+
+    /// Юніти, яких можна атакувати зараз.
+    pub fn attack_targets(&self, id: EntityID) -> Vec<EntityID> {
+        self.world.entities.iter()
+            .map(|t| t.id())
+            .filter(|&t| self.check_attack(id, t).is_ok())
+            .collect()
+    }
+
+    /// Перелічує всі ходи, атаки й вміння, які рушій
+    /// зараз прийме для цієї сутності (ті самі правила, без мутацій).
+    /// Ходи — це клітинки, досяжні шляхом у межах енергії.
+    pub fn legal_actions(&self, id: EntityID) -> LegalActions {
+        let mut legal = LegalActions::default();
+
+        let me = match self.world.get_entity(id) {
+            Some(e) => e,
+            None => return legal,
+        };
+        if self.validate_actor(id, 0).is_err() {
+            return legal;
+        }
+
+        legal.moves = self.reachable_tiles(id);
+        legal.attacks = self.attack_targets(id);
+
+        for (slot, skill) in me.skills().iter().enumerate() {
+            for pos in me.position().diamond(self.skills.spec(skill.kind()).range) {
+                if self.check_skill(id, slot, pos).is_ok() {
+                    legal.skills.push((slot, pos));
+                }
+            }
+        }
+
+        legal
+    }
+
     // =========================================================================
     //                            PRIVATE HELPERS
     // =========================================================================
 
-    fn validate_actor(&self, id: EntityID, base_cost: u32) -> Result<(), ActionResult> {
+    fn validate_actor(&self, id: EntityID, base_cost: u32) -> Result<(), EngineError> {
         let ent = self.world.get_entity(id)
-            .ok_or_else(|| ActionResult::Fail("Entity not found".to_string()))?;
+            .filter(|e| !e.is_dead())
+            .ok_or(EngineError::EntityNotFound(id))?;
 
         if ent.team() != self.world.current_team_turn {
-            return Err(ActionResult::Fail("Not your turn!".to_string()));
+            return Err(EngineError::NotYourTurn { team: ent.team(), current_team: self.world.current_team_turn });
+        }
+
+        if ent.is_stunned() {
+            return Err(EngineError::Stunned { turns_left: ent.stunned_for_turns() });
         }
 
         if !ent.can_act(base_cost) {
-            return Err(ActionResult::Fail("Not enough energy".to_string()));
+            return Err(EngineError::NotEnoughEnergy { required: base_cost, available: ent.energy() });
         }
 
         Ok(())
     }
 
+    /// Перевіряє хід і повертає шлях до цілі та його вартість в енергії.
+    fn plan_move(&self, id: EntityID, target_pos: MapPosition) -> Result<(Vec<MapPosition>, u32), EngineError> {
+        self.validate_actor(id, MOVE_COST)?;
+
+        if self.world.get_entity(id).unwrap().is_rooted() {
+            return Err(EngineError::Rooted);
+        }
+
+        if !self.world.map.is_standable(target_pos) {
+            let tile = self.world.map.get_tile(target_pos);
+            return Err(EngineError::PositionBlocked {
+                pos: target_pos,
+                tile: tile.map(|t| t.tile_type()),
+                occupant: tile.and_then(|t| t.entity_id()),
+            });
+        }
+
+        let me = self.world.get_entity(id).unwrap();
+        let from = me.position();
+        let path = Pathfinding::find_path(&self.world.map, from, target_pos)
+            .ok_or(EngineError::NoPath { from, to: target_pos })?;
+
+        let distance = path.len() as u32;
+        let cost = distance * MOVE_COST;
+
+        if !me.can_act(cost) {
+            return Err(EngineError::NotEnoughEnergyForDistance { distance, required: cost, available: me.energy() });
+        }
+
+        Ok((path, cost))
+    }
+
+    fn check_attack(&self, attacker_id: EntityID, target_id: EntityID) -> Result<(), EngineError> {
+        self.validate_actor(attacker_id, ATTACK_COST)?;
+
+        if attacker_id == target_id {
+            return Err(EngineError::CannotAttackSelf);
+        }
+
+        let att = self.world.get_entity(attacker_id).unwrap();
+        let tgt = self.world.get_entity(target_id)
+            .filter(|t| !t.is_dead())
+            .ok_or(EngineError::TargetLost(target_id))?;
+
+        let distance = att.position().manhattan_distance(&tgt.position());
+        if distance > att.attack_range() {
+            return Err(EngineError::OutOfRange { distance, range: att.attack_range() });
+        }
+
+        Ok(())
+    }
+
+    /// Перевіряє вміння: слот, перезарядку, енергію, дальність і форму цілі.
+    /// Для ривка (`SkillEffect::Dash`) повертає й шлях до цілі; інакше шлях порожній.
+    fn check_skill(&self, id: EntityID, slot: usize, target: MapPosition) -> Result<(SkillSpec, Vec<MapPosition>), EngineError> {
+        self.validate_actor(id, 0)?;
+
+        let me = self.world.get_entity(id).unwrap();
+        let skill = me.skills().get(slot).ok_or(EngineError::SkillNotFound(slot))?;
+        let spec = self.skills.spec(skill.kind());
+        let is_dash = spec.effect == SkillEffect::Dash;
+
+        if is_dash && me.is_rooted() {
+            return Err(EngineError::Rooted);
+        }
+        if !skill.is_ready() {
+            return Err(EngineError::SkillOnCooldown { turns_left: skill.cooldown_left() });
+        }
+        if !me.can_act(spec.energy_cost) {
+            return Err(EngineError::NotEnoughEnergy { required: spec.energy_cost, available: me.energy() });
+        }
+
+        let distance = me.position().manhattan_distance(&target);
+        if distance > spec.range {
+            return Err(EngineError::OutOfRange { distance, range: spec.range });
+        }
+
+        let occupant = self.world.get_entity_id_at(target)
+            .and_then(|t| self.world.get_entity(t))
+            .filter(|t| !t.is_dead());
+        let valid = match spec.shape {
+            TargetShape::Enemy => occupant.is_some_and(|t| t.team() != me.team()),
+            TargetShape::Ally => occupant.is_some_and(|t| t.team() == me.team()),
+            TargetShape::Area { .. } => self.world.map.get_tile(target).is_some(),
+            TargetShape::EmptyTile => self.world.map.is_standable(target),
+        };
+        if !valid || (is_dash && !self.world.map.is_standable(target)) {
+            return Err(EngineError::InvalidSkillTarget(target));
+        }
+
+        // Ривок іде тими ж правилами, що й хід: лише вільними клітинками, не крізь стіни.
+        let mut path = Vec::new();
+        if is_dash {
+            let from = me.position();
+            path = Pathfinding::find_path(&self.world.map, from, target)
+                .ok_or(EngineError::NoPath { from, to: target })?;
+            let steps = path.len() as u32;
+            if steps > spec.range {
+                return Err(EngineError::OutOfRange { distance: steps, range: spec.range });
+            }
+        }
+
+        Ok((spec.clone(), path))
+    }
+
+    /// Завдає шкоди й обробляє смерть. Повертає true, якщо ціль загинула.
+    /// У подію потрапляє шкода, що пройшла крізь Shield.
+    fn deal_damage(&mut self, attacker_id: EntityID, target_id: EntityID, amount: u32) -> bool {
+        let target = match self.world.get_entity_mut(target_id) {
+            Some(t) => t,
+            None => return false,
+        };
+        let before = target.health();
+        let target_died = target.take_damage(amount);
+        let dealt = before - target.health();
+        self.events.push(GameEvent::DamageDealt { attacker: attacker_id, target: target_id, amount: dealt });
+
+        if target_died {
+            self.handle_death(target_id, Some(attacker_id));
+        }
+        target_died
+    }
+
+    fn handle_death(&mut self, id: EntityID, killer: Option<EntityID>) {
+        if let Some(pos) = self.world.get_entity(id).map(|e| e.position()) {
+            self.clear_map_tile(pos);
+        }
+        self.events.push(GameEvent::EntityDied { entity: id, killer });
+        self.check_team_eliminated(id);
+    }
+
+    /// Початок ходу юніта: Poison шкодить, Regeneration лікує.
+    fn start_turn_effects(&mut self, id: EntityID) {
+        let e = match self.world.get_entity_mut(id) {
+            Some(e) => e,
+            None => return,
+        };
+
+        let regen = e.status(StatusKind::Regeneration).map_or(0, |s| s.potency());
+        if regen > 0 {
+            let before = e.health();
+            e.heal(regen);
+            let amount = e.health() - before;
+            self.events.push(GameEvent::EntityHealed { entity: id, amount });
+        }
+
+        let e = self.world.get_entity_mut(id).unwrap();
+        let poison = e.status(StatusKind::Poison).map_or(0, |s| s.potency());
+        if poison > 0 {
+            let before = e.health();
+            let died = e.take_damage(poison);
+            let amount = before - e.health();
+            self.events.push(GameEvent::PoisonDamage { entity: id, amount });
+            if died {
+                self.handle_death(id, None);
+            }
+        }
+    }
+
     fn apply_energy_cost(&mut self, id: EntityID, cost: u32) {
         if let Some(e) = self.world.get_entity_mut(id) {
             e.consume_energy(cost);
@@ -153,7 +604,7 @@ impl GameEngine {
     }
 
     fn clear_map_tile(&mut self, pos: MapPosition) {
-        if let Some(tile) = self.world.map.get_tile_mut(&pos) {
+        if let Some(tile) = self.world.map.get_tile_mut(pos) {
             tile.set_entity(None);
         }
     }
@@ -161,9 +612,90 @@ impl GameEngine {
     fn update_map_placement(&mut self, old_pos: MapPosition, new_pos: MapPosition, id: EntityID) {
         self.clear_map_tile(old_pos);
 
-        if let Some(new_tile) = self.world.map.get_tile_mut(&new_pos) {
+        if let Some(new_tile) = self.world.map.get_tile_mut(new_pos) {
             new_tile.set_entity(Some(id));
-            new_tile.take_powerup();
         }
     }
+
+    /// Перевіряє умови перемоги після команди, що почала писати події з `first_event`.
+    fn evaluate_outcome(&mut self, first_event: usize) {
+        let events = &self.events[first_event..];
+        let decided = self.win_conditions.iter().find_map(|cond| {
+            cond.check(&self.world, self.turn, self.started_teams, events)
+                .map(|winner| (winner, cond.clone()))
+        });
+
+        if let Some((winner, condition)) = decided {
+            self.outcome = Some(GameOutcome { winner, turn: self.turn, condition });
+            self.events.push(GameEvent::GameOver { winner, turn: self.turn });
+        }
+    }
+
+    /// Якщо загиблий був останнім живим у своїй команді — подія елімінації.
+    fn check_team_eliminated(&mut self, dead_id: EntityID) {
+        let team = match self.world.get_entity(dead_id) {
+            Some(e) => e.team(),
+            None => return,
+        };
+        let survivors = self.world.entities.iter().any(|e| e.team() == team && !e.is_dead());
+        if !survivors {
+            self.events.push(GameEvent::TeamEliminated { team });
+        }
+    }
+
+    /// Переводить юніта вздовж уже перевіреного шляху: тайли, подія руху
+    /// і паверапи на кожній пройденій клітинці.
+    fn follow_path(&mut self, id: EntityID, path: &[MapPosition]) {
+        let (from, to) = match (self.world.get_entity(id), path.last()) {
+            (Some(e), Some(&to)) => (e.position(), to),
+            _ => return,
+        };
+
+        if let Some(e) = self.world.get_entity_mut(id) {
+            e.set_position(to);
+        }
+        self.update_map_placement(from, to, id);
+        self.events.push(GameEvent::EntityMoved { entity: id, from, to, path: path.to_vec() });
+
+        for &pos in path {
+            self.trigger_tile(id, pos);
+        }
+    }
+
+    /// Спрацьовування клітинки, через яку пройшов юніт (паверапи тощо).
+    fn trigger_tile(&mut self, id: EntityID, pos: MapPosition) {
+        let powerup = match self.world.map.get_tile_mut(pos) {
+            Some(tile) => tile.take_powerup(),
+            None => return,
+        };
+        if powerup.is_some() {
+            self.events.push(GameEvent::PowerupPicked { entity: id, powerup, pos });
+            self.apply_powerup(id, powerup);
+        }
+    }
+
+    fn apply_powerup(&mut self, id: EntityID, powerup: PowerupType) {
+        let e = match self.world.get_entity_mut(id) {
+            Some(e) => e,
+            None => return,
+        };
+
+        let event = match powerup {
+            PowerupType::HealingPotion => {
+                let before = e.health();
+                e.heal(HEAL_AMOUNT);
+                GameEvent::EntityHealed { entity: id, amount: e.health() - before }
+            }
+            PowerupType::SpeedBoost => {
+                e.boost_energy(SPEED_BOOST_ENERGY);
+                GameEvent::EnergyBoosted { entity: id, amount: SPEED_BOOST_ENERGY }
+            }
+            PowerupType::WallBreaker => {
+                e.add_wall_breaker();
+                GameEvent::WallBreakerGained { entity: id, charges: e.wall_breaker_charges() }
+            }
+            PowerupType::None => return,
+        };
+        self.events.push(event);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,66 @@
+// src/state/modes/game/error.rs
+
+use std::fmt;
+use crate::specials::entity::EntityID;
+use crate::map::position::MapPosition;
+use crate::map::tile::TileType;
+
+/// Причина, з якої рушій відхилив команду.
+/// `Display` дає коротке повідомлення для UI; поля — контекст для ботів.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EngineError {
+    EntityNotFound(EntityID),
+    NotYourTurn { team: u32, current_team: u32 },
+    Stunned { turns_left: u32 },
+    /// Юніт під ефектом Rooted і не може пересуватись.
+    Rooted,
+    NotEnoughEnergy { required: u32, available: u32 },
+    NotEnoughEnergyForDistance { distance: u32, required: u32, available: u32 },
+    /// `tile` = `None`, якщо позиція поза картою; `occupant` — хто там стоїть.
+    PositionBlocked { pos: MapPosition, tile: Option<TileType>, occupant: Option<EntityID> },
+    NoPath { from: MapPosition, to: MapPosition },
+    CannotAttackSelf,
+    TargetLost(EntityID),
+    /// На цільовій клітинці немає сутності.
+    NoTarget(MapPosition),
+    /// У юніта немає зарядів паверапа для використання.
+    NoPowerup,
+    NotAWall(MapPosition),
+    OutOfRange { distance: u32, range: u32 },
+    /// У юніта немає вміння в цьому слоті.
+    SkillNotFound(usize),
+    SkillOnCooldown { turns_left: u32 },
+    /// Клітинка не підходить під форму цілі вміння.
+    InvalidSkillTarget(MapPosition),
+    GameOver { winner: Option<u32> },
+    /// Індекс дискретної дії поза простором дій агента.
+    ActionIndexOutOfRange { index: usize, size: usize },
+}
+
+impl fmt::Display for EngineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EngineError::EntityNotFound(_) => write!(f, "Entity not found"),
+            EngineError::NotYourTurn { .. } => write!(f, "Not your turn!"),
+            EngineError::Stunned { .. } => write!(f, "Unit is stunned"),
+            EngineError::Rooted => write!(f, "Unit is rooted"),
+            EngineError::NotEnoughEnergy { .. } => write!(f, "Not enough energy"),
+            EngineError::NotEnoughEnergyForDistance { .. } => write!(f, "Not enough energy for distance"),
+            EngineError::PositionBlocked { .. } => write!(f, "Position blocked"),
+            EngineError::NoPath { .. } => write!(f, "No path to target"),
+            EngineError::CannotAttackSelf => write!(f, "Cannot attack self"),
+            EngineError::TargetLost(_) => write!(f, "Target lost"),
+            EngineError::NoTarget(_) => write!(f, "No target there"),
+            EngineError::NoPowerup => write!(f, "No powerup to use"),
+            EngineError::NotAWall(_) => write!(f, "Target is not a wall"),
+            EngineError::OutOfRange { .. } => write!(f, "Target out of range"),
+            EngineError::SkillNotFound(_) => write!(f, "No such skill"),
+            EngineError::SkillOnCooldown { turns_left } => write!(f, "Skill on cooldown ({} turns)", turns_left),
+            EngineError::InvalidSkillTarget(_) => write!(f, "Invalid skill target"),
+            EngineError::GameOver { .. } => write!(f, "Game is over"),
+            EngineError::ActionIndexOutOfRange { index, size } => write!(f, "Invalid action index {} (size {})", index, size),
+        }
+    }
+}
+
+impl std::error::Error for EngineError {}
@@ -0,0 +1,35 @@
+// src/state/modes/game/events.rs
+
+use serde::{Serialize, Deserialize};
+use crate::specials::entity::EntityID;
+use crate::map::position::MapPosition;
+use crate::specials::powerup::PowerupType;
+use crate::specials::skill::SkillKind;
+use crate::specials::status::StatusKind;
+
+/// Факт, що стався в симуляції внаслідок команди.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum GameEvent {
+    /// `path` — пройдені клітинки без стартової.
+    EntityMoved { entity: EntityID, from: MapPosition, to: MapPosition, path: Vec<MapPosition> },
+    DamageDealt { attacker: EntityID, target: EntityID, amount: u32 },
+    /// `killer` — хто завдав смертельного удару, якщо відомо.
+    EntityDied { entity: EntityID, killer: Option<EntityID> },
+    PowerupPicked { entity: EntityID, powerup: PowerupType, pos: MapPosition },
+    EntityHealed { entity: EntityID, amount: u32 },
+    /// Тимчасова енергія понад максимум (до кінця ходу).
+    EnergyBoosted { entity: EntityID, amount: u32 },
+    WallBreakerGained { entity: EntityID, charges: u32 },
+    WallDestroyed { entity: EntityID, pos: MapPosition },
+    /// Юніт застосував вміння (наслідки — окремими подіями далі).
+    SkillUsed { entity: EntityID, skill: SkillKind, target: MapPosition },
+    StatusApplied { entity: EntityID, status: StatusKind, turns: u32 },
+    StatusExpired { entity: EntityID, status: StatusKind },
+    /// Шкода від Poison на початку ходу (без атакуючого).
+    PoisonDamage { entity: EntityID, amount: u32 },
+    TurnStarted { team: u32 },
+    /// Загинув останній юніт команди.
+    TeamEliminated { team: u32 },
+    /// Спрацювала умова перемоги; `winner = None` — нічия.
+    GameOver { winner: Option<u32>, turn: u32 },
+}
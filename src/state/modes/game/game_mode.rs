@@ -1,25 +1,67 @@
+use std::collections::HashSet;
+
 use crate::state::world_state::WorldState;
 use crate::specials::entity::EntityID;
 use crate::map::position::MapPosition;
+use crate::specials::skill::{SkillKind, SkillSpec};
 use crate::state::actions::{GameTool, Action};
+use crate::state::camera::{Camera, SCROLL_STEP};
 
-use super::engine::{GameEngine, ActionResult, TurnResult};
+use super::engine::GameEngine;
+use super::victory::{GameOutcome, WinCondition};
+use super::command::EngineCommand;
+use super::events::GameEvent;
 use super::ai::AiSystem;
+use super::log::CombatLog;
+
+/// Куди експортується бойовий журнал.
+const LOG_EXPORT_PATH: &str = "combat_log.txt";
+
+/// Підказка для інструмента Move: куди юніт може дійти і шлях до курсора.
+#[derive(Debug, Clone, Default)]
+pub struct MovePreview {
+    pub reachable: Vec<MapPosition>,
+    /// Шлях до клітинки під курсором і його вартість (якщо хід можливий).
+    pub path: Option<(Vec<MapPosition>, u32)>,
+}
+
+/// Підказка для інструмента Attack: клітинки в межах дальності і позиції цілей.
+#[derive(Debug, Clone, Default)]
+pub struct AttackPreview {
+    pub in_range: Vec<MapPosition>,
+    pub targets: Vec<MapPosition>,
+}
 
 pub struct GameMode {
     engine: GameEngine,
     selected_entity_id: Option<EntityID>,
     debug_message: String,
     current_tool: GameTool,
+    selected_skill: usize,
+    camera: Camera,
+    cursor: MapPosition,
+    /// `Some` — карта загроз увімкнена (перераховується після кожної дії).
+    danger_map: Option<HashSet<MapPosition>>,
+    combat_log: CombatLog,
 }
 
 impl GameMode {
     pub fn new(world_state: WorldState) -> Self {
+        // Курсор стартує на першому юніті команди, що ходить
+        let cursor = world_state.entities.iter()
+            .find(|e| e.team() == world_state.current_team_turn && !e.is_dead())
+            .map_or(MapPosition::new(0, 0), |e| e.position());
+
         GameMode {
             engine: GameEngine::new(world_state),
             selected_entity_id: None,
             debug_message: String::from("Game Start. Select a unit."),
             current_tool: GameTool::Select,
+            selected_skill: 0,
+            camera: Camera::default(),
+            cursor,
+            danger_map: None,
+            combat_log: CombatLog::new(),
         }
     }
 
@@ -39,10 +81,82 @@ impl GameMode {
         self.selected_entity_id
     }
 
+    /// Слот вміння, яке застосує інструмент Skill.
+    pub fn selected_skill(&self) -> usize {
+        self.selected_skill
+    }
+
+    /// Параметри вміння з таблиці рушія (назва, ціна, дальність).
+    pub fn skill_spec(&self, kind: SkillKind) -> &SkillSpec {
+        self.engine.skill_spec(kind)
+    }
+
+    pub fn camera(&self) -> &Camera {
+        &self.camera
+    }
+
+    pub fn cursor(&self) -> MapPosition {
+        self.cursor
+    }
+
+    pub fn combat_log(&self) -> &CombatLog {
+        &self.combat_log
+    }
+
     pub fn debug_message(&self) -> &str {
         &self.debug_message
     }
 
+    pub fn turn(&self) -> u32 {
+        self.engine.turn()
+    }
+
+    /// `Some`, коли гру завершено (показується екран Game Over).
+    pub fn outcome(&self) -> Option<&GameOutcome> {
+        self.engine.outcome()
+    }
+
+    pub fn win_conditions(&self) -> &[WinCondition] {
+        self.engine.win_conditions()
+    }
+
+    /// `Some`, коли активний Move і обрано юніта поточної команди.
+    pub fn move_preview(&self) -> Option<MovePreview> {
+        if self.current_tool != GameTool::Move || self.engine.is_over() {
+            return None;
+        }
+        let id = self.selected_entity_id?;
+        let reachable = self.engine.reachable_tiles(id);
+        if reachable.is_empty() {
+            return None;
+        }
+        let path = self.engine.preview_move(id, self.cursor).ok();
+        Some(MovePreview { reachable, path })
+    }
+
+    /// `Some`, коли активний Attack і обрано живого юніта.
+    pub fn attack_preview(&self) -> Option<AttackPreview> {
+        if self.current_tool != GameTool::Attack || self.engine.is_over() {
+            return None;
+        }
+        let world = self.engine.world();
+        let me = world.get_entity(self.selected_entity_id?).filter(|e| !e.is_dead())?;
+
+        let in_range = me.position().diamond(me.attack_range()).into_iter()
+            .filter(|p| *p != me.position() && world.map.in_bounds(*p))
+            .collect();
+        let targets = self.engine.attack_targets(me.id()).into_iter()
+            .filter_map(|id| world.get_entity(id))
+            .map(|e| e.position())
+            .collect();
+        Some(AttackPreview { in_range, targets })
+    }
+
+    /// Клітинки під загрозою ворога в його наступний хід (якщо карту ввімкнено).
+    pub fn danger_map(&self) -> Option<&HashSet<MapPosition>> {
+        self.danger_map.as_ref()
+    }
+
     // =========================================================================
     //                            MAIN LOOP
     // =========================================================================
@@ -65,13 +179,61 @@ impl GameMode {
     //                            INPUT HANDLERS
     // =========================================================================
 
+    pub fn scroll_camera(&mut self, dx: i32, dy: i32) {
+        self.camera.scroll(dx, dy, &self.engine.world().map);
+    }
+
+    pub fn set_viewport(&mut self, width: i32, height: i32) {
+        let first_frame = self.camera.view_size() == (0, 0);
+        self.camera.set_viewport(width, height, &self.engine.world().map);
+        if first_frame {
+            self.camera.center_on(self.cursor, &self.engine.world().map);
+        }
+    }
+
+    /// Зсуває клавіатурний курсор у межах карти; камера стежить за ним.
+    pub fn move_cursor(&mut self, dx: i32, dy: i32) {
+        let map = &self.engine.world().map;
+        let next = self.cursor.offset(dx, dy);
+        if map.in_bounds(next) {
+            self.cursor = next;
+            self.camera.ensure_visible(next, map);
+        }
+    }
+
+    /// Гортає журнал (додатне `delta` — до старіших записів).
+    pub fn scroll_log(&mut self, delta: i32) {
+        self.combat_log.scroll_by(delta);
+    }
+
     pub fn handle_keypress(&mut self, key: char) -> Option<Action> {
+        if self.engine.is_over() {
+            match key {
+                'q' | 'Q' => return Some(Action::BackToMenu),
+                'e' | 'E' => self.export_log(),
+                _ => {}
+            }
+            return None;
+        }
         
         match key {
             '1' => self.switch_tool(GameTool::Select),
             '2' => self.switch_tool(GameTool::Move),
             '3' => self.switch_tool(GameTool::Attack),
             '4' => self.switch_tool(GameTool::Skill),
+            '5' => self.switch_tool(GameTool::Powerup),
+            'n' | 'N' => self.cycle_skill(),
+            'w' | 'W' => self.scroll_camera(0, -SCROLL_STEP),
+            's' | 'S' => self.scroll_camera(0, SCROLL_STEP),
+            'a' | 'A' => self.scroll_camera(-SCROLL_STEP, 0),
+            'd' | 'D' => self.scroll_camera(SCROLL_STEP, 0),
+            'c' | 'C' => self.center_on_selected(),
+            'x' | 'X' => self.toggle_danger_map(),
+            'e' | 'E' => self.export_log(),
+            'h' | 'H' => self.move_cursor(-1, 0),
+            'j' | 'J' => self.move_cursor(0, 1),
+            'k' | 'K' => self.move_cursor(0, -1),
+            'l' | 'L' => self.move_cursor(1, 0),
             't' | 'T' => self.end_turn_logic(),
             'q' | 'Q' => return Some(Action::BackToMenu),
             _ => {}
@@ -81,26 +243,40 @@ impl GameMode {
     }
 
     pub fn handle_menu_click(&mut self, _screen_x: i32, screen_y: i32) -> Option<Action> {
+        if self.engine.is_over() {
+            return None;
+        }
     
         match screen_y {
             3 => self.switch_tool(GameTool::Select),
             4 => self.switch_tool(GameTool::Move),
             5 => self.switch_tool(GameTool::Attack),
+            6 if self.current_tool == GameTool::Skill => self.cycle_skill(),
             6 => self.switch_tool(GameTool::Skill),
-            8 => self.end_turn_logic(),
-            10 => return Some(Action::BackToMenu),
+            7 => self.switch_tool(GameTool::Powerup),
+            8 => self.toggle_danger_map(),
+            10 => self.end_turn_logic(),
+            12 => return Some(Action::BackToMenu),
             _ => {}
         }
         None
     }
 
     pub fn handle_click(&mut self, pos: MapPosition) {
+        if self.engine.is_over() {
+            return;
+        }
+
+        if self.engine.world().map.in_bounds(pos) {
+            self.cursor = pos;
+        }
 
         match self.current_tool {
             GameTool::Select => self.do_select(pos),
             GameTool::Move => self.do_move(pos),
             GameTool::Attack => self.do_attack(pos),
-            GameTool::Skill => self.debug_message = "Skills not implemented yet".to_string(),
+            GameTool::Skill => self.do_skill(pos),
+            GameTool::Powerup => self.do_use_powerup(pos),
         }
     }
 
@@ -113,31 +289,73 @@ impl GameMode {
         self.debug_message = format!("Tool: {:?}", tool);
     }
 
+    fn export_log(&mut self) {
+        self.debug_message = match self.combat_log.export(LOG_EXPORT_PATH) {
+            Ok(()) => format!("Log saved to '{}'", LOG_EXPORT_PATH),
+            Err(e) => format!("Log export failed: {}", e),
+        };
+    }
+
+    fn toggle_danger_map(&mut self) {
+        if self.danger_map.take().is_some() {
+            self.debug_message = "Danger map: OFF".to_string();
+        } else {
+            self.refresh_danger_map_for(self.engine.current_team());
+            self.debug_message = "Danger map: ON".to_string();
+        }
+    }
+
+    fn refresh_danger_map_for(&mut self, team: u32) {
+        self.danger_map = Some(self.engine.threat_map(team));
+    }
+
+    fn center_on_selected(&mut self) {
+        let pos = self.selected_entity_id
+            .and_then(|id| self.engine.world().get_entity(id))
+            .map(|e| e.position());
+        match pos {
+            Some(pos) => self.camera.center_on(pos, &self.engine.world().map),
+            None => self.debug_message = "Select a unit first!".to_string(),
+        }
+    }
+
+    /// Тримає обраного юніта в кадрі (після руху, Dash тощо).
+    fn follow_selected(&mut self) {
+        let pos = self.selected_entity_id
+            .and_then(|id| self.engine.world().get_entity(id))
+            .map(|e| e.position());
+        if let Some(pos) = pos {
+            self.camera.ensure_visible(pos, &self.engine.world().map);
+        }
+    }
+
+    /// Перемикає вміння обраного юніта по колу.
+    fn cycle_skill(&mut self) {
+        let skills = match self.selected_entity_id.and_then(|id| self.engine.world().get_entity(id)) {
+            Some(e) if !e.skills().is_empty() => e.skills(),
+            _ => { self.debug_message = "Select a unit with skills first!".to_string(); return; }
+        };
+
+        self.selected_skill = (self.selected_skill + 1) % skills.len();
+        let name = &self.engine.skill_spec(skills[self.selected_skill].kind()).name;
+        self.debug_message = format!("Skill: {}", name);
+        self.current_tool = GameTool::Skill;
+    }
+
     fn end_turn_logic(&mut self) {
         // 1. Спочатку ходять БОТИ поточної команди (якщо вони є)
         // Це реалізує логіку: Гравець походив -> Натиснув T -> Боти доробили роботу
         let current_team = self.engine.current_team();
-        let ai_logs = AiSystem::perform_turn(&mut self.engine, current_team);
-        
-        if !ai_logs.is_empty() {
-            // Показуємо, що зробили боти
-            self.debug_message = ai_logs.last().unwrap().clone();
-        }
+        AiSystem::perform_turn(&mut self.engine, current_team);
+        // Дії ботів пишемо в журнал ще під номером цього ходу
+        self.flush_events();
 
         // 2. Тільки після цього передаємо хід наступній команді
-        match self.engine.end_turn() {
-            TurnResult::TurnChanged(new_team) => {
-                let name = if new_team == 1 { "Player" } else { "Enemy" };
-                // Додаємо інформацію до попереднього повідомлення або перезаписуємо
-                self.debug_message = format!("Turn: {} (Team {})", name, new_team);
-                
-                if new_team == 1 {
-                    self.current_tool = GameTool::Select;
-                }
-            }
-            TurnResult::TurnContinues => {
-                self.debug_message = "Waiting for others...".to_string();
-            }
+        let _ = self.engine.apply(EngineCommand::EndTurn);
+
+        self.flush_events();
+        if self.engine.current_team() == 1 {
+            self.current_tool = GameTool::Select;
         }
     }
 
@@ -151,6 +369,7 @@ impl GameMode {
         }
         
         self.selected_entity_id = clicked_id;
+        self.selected_skill = 0;
         
         if let Some(new_id) = clicked_id {
             let name = if let Some(e) = self.engine.world_mut().get_entity_mut(new_id) { 
@@ -160,6 +379,7 @@ impl GameMode {
                 "Unknown".to_string()
             };
             self.debug_message = format!("Selected: {}", name);
+            self.center_on_selected();
         } else {
              self.debug_message = "Selection cleared".to_string();
         }
@@ -171,10 +391,9 @@ impl GameMode {
             None => { self.debug_message = "Select a unit first!".to_string(); return; }
         };
 
-        match self.engine.move_entity(id, target_pos) {
-            ActionResult::SuccessMove => self.debug_message = "Moved successfully.".to_string(),
-            ActionResult::Fail(reason) => self.debug_message = format!("Move failed: {}", reason),
-            _ => {}
+        match self.engine.apply(EngineCommand::Move { entity: id, to: target_pos }) {
+            Ok(_) => { self.flush_events(); self.follow_selected(); },
+            Err(reason) => self.debug_message = format!("Move failed: {}", reason),
         }
     }
 
@@ -189,16 +408,131 @@ impl GameMode {
             None => { self.debug_message = "Click on an enemy!".to_string(); return; }
         };
 
-        match self.engine.attack_entity(attacker_id, target_id) {
-            ActionResult::SuccessAttack { damage, target_died } => {
-                if target_died {
-                    self.debug_message = format!("FATAL HIT! -{} HP", damage);
-                } else {
-                    self.debug_message = format!("Hit! -{} HP", damage);
-                }
-            },
-            ActionResult::Fail(reason) => self.debug_message = format!("Attack failed: {}", reason),
-            _ => {}
+        match self.engine.apply(EngineCommand::Attack { attacker: attacker_id, target: target_id }) {
+            Ok(_) => { self.flush_events(); },
+            Err(reason) => self.debug_message = format!("Attack failed: {}", reason),
+        }
+    }
+
+    fn do_skill(&mut self, pos: MapPosition) {
+        let id = match self.selected_entity_id {
+            Some(id) => id,
+            None => { self.debug_message = "Select a unit first!".to_string(); return; }
+        };
+
+        match self.engine.apply(EngineCommand::UseSkill { entity: id, skill: self.selected_skill, target: pos }) {
+            Ok(_) => { self.flush_events(); self.follow_selected(); },
+            Err(reason) => self.debug_message = format!("Skill failed: {}", reason),
+        }
+    }
+
+    fn do_use_powerup(&mut self, pos: MapPosition) {
+        let id = match self.selected_entity_id {
+            Some(id) => id,
+            None => { self.debug_message = "Select a unit first!".to_string(); return; }
+        };
+
+        match self.engine.apply(EngineCommand::UsePowerup { entity: id, target: pos }) {
+            Ok(_) => { self.flush_events(); },
+            Err(reason) => self.debug_message = format!("Powerup failed: {}", reason),
+        }
+    }
+
+    // =========================================================================
+    //                            EVENT HANDLING
+    // =========================================================================
+
+    /// Забирає події з рушія, пише їх у журнал і показує останню.
+    fn flush_events(&mut self) {
+        if self.danger_map.is_some() {
+            self.refresh_danger_map_for(self.engine.current_team());
+        }
+
+        let events = self.engine.drain_events();
+        let turn = self.engine.turn();
+        for ev in &events {
+            let text = self.describe_event(ev);
+            let team = self.event_team(ev);
+            self.combat_log.push(turn, team, text);
+        }
+        if let Some(last) = events.last() {
+            self.debug_message = self.describe_event(last);
+        }
+    }
+
+    /// Команда, якій належить подія (для кольору в журналі).
+    fn event_team(&self, event: &GameEvent) -> Option<u32> {
+        let entity = match event {
+            GameEvent::EntityMoved { entity, .. }
+            | GameEvent::EntityDied { entity, .. }
+            | GameEvent::PowerupPicked { entity, .. }
+            | GameEvent::EntityHealed { entity, .. }
+            | GameEvent::EnergyBoosted { entity, .. }
+            | GameEvent::WallBreakerGained { entity, .. }
+            | GameEvent::WallDestroyed { entity, .. }
+            | GameEvent::SkillUsed { entity, .. }
+            | GameEvent::StatusApplied { entity, .. }
+            | GameEvent::StatusExpired { entity, .. }
+            | GameEvent::PoisonDamage { entity, .. } => *entity,
+            GameEvent::DamageDealt { attacker, .. } => *attacker,
+            GameEvent::TurnStarted { team } | GameEvent::TeamEliminated { team } => return Some(*team),
+            GameEvent::GameOver { winner, .. } => return *winner,
+        };
+        self.engine.world().get_entity(entity).map(|e| e.team())
+    }
+
+    fn describe_event(&self, event: &GameEvent) -> String {
+        match event {
+            GameEvent::EntityMoved { entity, path, .. } => {
+                format!("{} moved {} tiles.", self.unit_label(*entity), path.len())
+            }
+            GameEvent::DamageDealt { target, amount, .. } => {
+                format!("Hit {}! -{} HP", self.unit_label(*target), amount)
+            }
+            GameEvent::EntityDied { entity, .. } => {
+                format!("FATAL HIT! {} died.", self.unit_label(*entity))
+            }
+            GameEvent::PowerupPicked { entity, powerup, .. } => {
+                format!("{} picked up {:?}", self.unit_label(*entity), powerup)
+            }
+            GameEvent::EntityHealed { entity, amount } => {
+                format!("{} healed +{} HP", self.unit_label(*entity), amount)
+            }
+            GameEvent::EnergyBoosted { entity, amount } => {
+                format!("{} boosted +{} EN this turn", self.unit_label(*entity), amount)
+            }
+            GameEvent::WallBreakerGained { entity, charges } => {
+                format!("{} can break walls ({} charges)", self.unit_label(*entity), charges)
+            }
+            GameEvent::WallDestroyed { entity, pos } => {
+                format!("{} smashed the wall at ({}, {})", self.unit_label(*entity), pos.x(), pos.y())
+            }
+            GameEvent::SkillUsed { entity, skill, .. } => {
+                format!("{} used {}", self.unit_label(*entity), self.engine.skill_spec(*skill).name)
+            }
+            GameEvent::StatusApplied { entity, status, turns } => {
+                format!("{} gets {} for {} turn(s)", self.unit_label(*entity), status.name(), turns)
+            }
+            GameEvent::StatusExpired { entity, status } => {
+                format!("{} is no longer affected by {}", self.unit_label(*entity), status.name())
+            }
+            GameEvent::PoisonDamage { entity, amount } => {
+                format!("{} takes {} poison damage", self.unit_label(*entity), amount)
+            }
+            GameEvent::TurnStarted { team } => {
+                let name = if *team == 1 { "Player" } else { "Enemy" };
+                format!("Turn: {} (Team {})", name, team)
+            }
+            GameEvent::TeamEliminated { team } => format!("Team {} eliminated!", team),
+            GameEvent::GameOver { winner: Some(team), turn } => format!("Team {} wins on turn {}!", team, turn),
+            GameEvent::GameOver { winner: None, turn } => format!("Draw on turn {}.", turn),
+        }
+    }
+
+    fn unit_label(&self, id: EntityID) -> String {
+        match self.engine.world().get_entity(id) {
+            Some(e) => format!("{} #{}", e.display_name(), id),
+            None => format!("#{}", id),
         }
     }
 }
\ No newline at end of file
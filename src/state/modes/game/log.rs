@@ -0,0 +1,72 @@
+// src/state/modes/game/log.rs
+
+use std::fs::File;
+use std::io::{self, Write};
+
+/// Скільки записів журналу видно в боковому меню одночасно.
+pub const LOG_VIEW_LINES: usize = 10;
+
+/// Один рядок бойового журналу.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogEntry {
+    pub turn: u32,
+    /// Команда, до якої стосується запис (для кольору); `None` — системний запис.
+    pub team: Option<u32>,
+    pub text: String,
+}
+
+/// Повна історія матчу з прокруткою для бокового меню.
+#[derive(Debug, Clone, Default)]
+pub struct CombatLog {
+    entries: Vec<LogEntry>,
+    /// Скільки останніх записів сховано під низом вікна (0 — показуємо найсвіжіші).
+    scroll: usize,
+}
+
+impl CombatLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, turn: u32, team: Option<u32>, text: String) {
+        self.entries.push(LogEntry { turn, team, text });
+        // Якщо гравець гортає історію — вікно лишається на місці
+        if self.scroll > 0 {
+            self.scroll += 1;
+        }
+    }
+
+    pub fn entries(&self) -> &[LogEntry] {
+        &self.entries
+    }
+
+    pub fn scroll(&self) -> usize {
+        self.scroll
+    }
+
+    /// Додатне `delta` — назад в історію, від'ємне — до свіжих записів.
+    pub fn scroll_by(&mut self, delta: i32) {
+        let max = self.entries.len().saturating_sub(LOG_VIEW_LINES);
+        let next = self.scroll as i64 + delta as i64;
+        self.scroll = next.clamp(0, max as i64) as usize;
+    }
+
+    /// Записи, що зараз потрапляють у вікно журналу.
+    pub fn visible(&self) -> &[LogEntry] {
+        let end = self.entries.len() - self.scroll.min(self.entries.len());
+        let start = end.saturating_sub(LOG_VIEW_LINES);
+        &self.entries[start..end]
+    }
+
+    /// Записує весь журнал у текстовий файл.
+    pub fn export(&self, path: &str) -> io::Result<()> {
+        let mut file = File::create(path)?;
+        for e in &self.entries {
+            match e.team {
+                Some(team) => writeln!(file, "[Turn {}] [Team {}] {}", e.turn, team, e.text)?,
+                None => writeln!(file, "[Turn {}] {}", e.turn, e.text)?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -3,8 +3,9 @@ use ratatui::text::{Line, Span};
 use ratatui::style::{Style, Color, Modifier};
 
 use crate::tui::menu::{MenuState, MenuItem};
-use crate::tui::utils::get_team_color;
+use crate::tui::utils::{get_team_color, get_status_color};
 use crate::state::actions::GameTool;
+use crate::specials::status::StatusKind;
 
 use super::game_mode::GameMode;
 
@@ -12,11 +13,11 @@ impl MenuState for GameMode {
     fn get_title(&self) -> String { " GAME ".to_string() }
 
     fn get_top_header(&self) -> Vec<Line<'_>> {
-        let turn = self.world().current_team_turn;
-        let color = get_team_color(turn);
+        let team = self.world().current_team_turn;
+        let color = get_team_color(team);
         vec![
             Line::from(Span::styled(
-                format!("--- TEAM {} TURN ---", turn), 
+                format!("--- TEAM {} TURN ({}) ---", team, self.turn()), 
                 Style::default().fg(color).add_modifier(Modifier::BOLD)
             ))
         ]
@@ -24,11 +25,20 @@ impl MenuState for GameMode {
 
     fn get_tools(&self) -> Vec<MenuItem> {
         let t = self.current_tool();
+        let skill_label = match self.selected_entity_id().and_then(|id| self.world().get_entity(id)) {
+            Some(e) => match e.skills().get(self.selected_skill()) {
+                Some(s) => format!("Skill: {}", self.skill_spec(s.kind()).name),
+                None => "Skill".to_string(),
+            },
+            None => "Skill".to_string(),
+        };
         vec![
             MenuItem::new("1", "Select", t == GameTool::Select),
             MenuItem::new("2", "Move", t == GameTool::Move),
             MenuItem::new("3", "Attack", t == GameTool::Attack),
-            MenuItem::new("4", "Skill", t == GameTool::Skill),
+            MenuItem::new("4", &skill_label, t == GameTool::Skill),
+            MenuItem::new("5", "Use Powerup", t == GameTool::Powerup),
+            MenuItem::new("X", "Danger Map", self.danger_map().is_some()),
             MenuItem::spacer(),
             MenuItem::colored("T", "End Turn", Color::Yellow),
             MenuItem::spacer(),
@@ -37,7 +47,11 @@ impl MenuState for GameMode {
     }
 
     fn get_info_section(&self) -> Vec<Line<'_>> {
-        let mut lines = Vec::new();
+        // Умови перемоги — над інфо про юніта (рядки інструментів вище фіксовані для кліків)
+        let mut lines: Vec<Line> = self.win_conditions().iter()
+            .map(|c| Line::from(Span::styled(format!("GOAL: {}", c.describe()), Style::default().fg(Color::Yellow))))
+            .collect();
+        lines.push(Line::from(""));
         
         if let Some(id) = self.selected_entity_id() {
             if let Some(e) = self.world().get_entity(id) {
@@ -51,6 +65,42 @@ impl MenuState for GameMode {
                 let team_color = get_team_color(e.team());
                 lines.push(Line::from(Span::styled(format!("TEAM: {}", e.team()), Style::default().fg(team_color))));
                 
+                if e.wall_breaker_charges() > 0 {
+                    let txt = format!("WALL BREAKER: x{}", e.wall_breaker_charges());
+                    lines.push(Line::from(Span::styled(txt, Style::default().fg(Color::Magenta))));
+                }
+
+                if !e.statuses().is_empty() {
+                    let mut spans = vec![Span::raw("FX:")];
+                    for s in e.statuses() {
+                        let txt = match s.kind() {
+                            StatusKind::Poison | StatusKind::Regeneration | StatusKind::Haste | StatusKind::Shield => {
+                                format!(" {} {}({})", s.kind().name(), s.potency(), s.turns_left())
+                            }
+                            _ => format!(" {}({})", s.kind().name(), s.turns_left()),
+                        };
+                        spans.push(Span::styled(txt, Style::default().fg(get_status_color(s.kind()))));
+                    }
+                    lines.push(Line::from(spans));
+                }
+
+                if let Some(preview) = self.move_preview() {
+                    let txt = match preview.path {
+                        Some((path, cost)) => format!("PATH: {} tiles, {} EN", path.len(), cost),
+                        None => "PATH: cursor out of reach".to_string(),
+                    };
+                    lines.push(Line::from(Span::styled(txt, Style::default().fg(Color::LightBlue))));
+                }
+
+                for (slot, s) in e.skills().iter().enumerate() {
+                    let spec = self.skill_spec(s.kind());
+                    let marker = if slot == self.selected_skill() { ">" } else { " " };
+                    let status = if s.is_ready() { "ready".to_string() } else { format!("cd {}", s.cooldown_left()) };
+                    let txt = format!("{}{} ({}EN, r{}) {}", marker, spec.name, spec.energy_cost, spec.range, status);
+                    let color = if s.is_ready() { Color::Green } else { Color::DarkGray };
+                    lines.push(Line::from(Span::styled(txt, Style::default().fg(color))));
+                }
+
                 if e.is_ai() {
                     lines.push(Line::from(Span::styled("(AI Controlled)", Style::default().fg(Color::DarkGray))));
                 }
@@ -61,7 +111,21 @@ impl MenuState for GameMode {
         lines
     }
 
-    fn get_logs(&self) -> String {
-        self.debug_message().to_string()
+    fn get_logs(&self) -> Vec<Line<'_>> {
+        let mut lines = vec![Line::from(self.debug_message()), Line::from("")];
+
+        let log = self.combat_log();
+        for e in log.visible() {
+            let color = e.team.map_or(Color::White, get_team_color);
+            lines.push(Line::from(vec![
+                Span::styled(format!("T{} ", e.turn), Style::default().add_modifier(Modifier::DIM)),
+                Span::styled(e.text.as_str(), Style::default().fg(color)),
+            ]));
+        }
+        if log.scroll() > 0 {
+            let hint = format!("(+{} newer, PgDn)", log.scroll());
+            lines.push(Line::from(Span::styled(hint, Style::default().add_modifier(Modifier::DIM))));
+        }
+        lines
     }
 }
\ No newline at end of file
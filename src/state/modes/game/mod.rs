@@ -1,10 +1,20 @@
 // src/state/modes/game/mod.rs
 
 pub mod engine;
+pub mod command;
+pub mod events;
+pub mod error;
+pub mod victory;
 pub mod ai;
 pub mod pathfinding;
+pub mod log;
 pub mod game_mode;  
 pub mod menu;
 
-pub use game_mode::GameMode;
-pub use engine::{GameEngine, ActionResult, TurnResult};
\ No newline at end of file
+pub use game_mode::{GameMode, MovePreview, AttackPreview};
+pub use engine::{GameEngine, ActionResult, TurnResult, LegalActions};
+pub use command::EngineCommand;
+pub use events::GameEvent;
+pub use error::EngineError;
+pub use victory::{WinCondition, GameOutcome};
+pub use log::{CombatLog, LogEntry};
\ No newline at end of file
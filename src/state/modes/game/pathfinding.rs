@@ -5,6 +5,9 @@ use crate::map::position::MapPosition;
 pub struct Pathfinding;
 
 impl Pathfinding {
+    /// Найкоротший шлях (BFS) від `start` до `goal`, без стартової клітинки.
+    /// Шлях іде лише прохідними вільними тайлами; сама ціль може бути зайнята
+    /// (так AI шукає дорогу до ворога).
     pub fn find_path(map: &Map, start: MapPosition, goal: MapPosition) -> Option<Vec<MapPosition>> {
         if start == goal { return Some(vec![]); }
 
@@ -24,9 +27,12 @@ impl Pathfinding {
             }
 
             for neighbor in current.neighbors() {
-                let is_walkable = map.get_tile(neighbor).map_or(false, |t| !t.is_solid());
-                let is_occupied = map.get_tile(neighbor).map_or(false, |t| !t.is_occupied());
-                if !(is_walkable || is_occupied){ continue; }
+                let passable = if neighbor == goal {
+                    map.is_walkable(neighbor)
+                } else {
+                    map.is_standable(neighbor)
+                };
+                if !passable { continue; }
 
                 if !visited.contains(&neighbor) {
                     visited.insert(neighbor);
@@ -41,11 +47,35 @@ impl Pathfinding {
             let mut curr = goal;
             while curr != start {
                 path.push(curr);
-                curr = *came_from.get(&curr).unwrap(); 
+                curr = *came_from.get(&curr).unwrap();
             }
             path.reverse();
             return Some(path);
         }
         None
     }
-}
\ No newline at end of file
+
+    /// Flood fill: усі вільні клітинки, досяжні з `start` не більше ніж за
+    /// `max_steps` кроків, з відстанню до кожної. Старт не входить у результат.
+    pub fn reachable(map: &Map, start: MapPosition, max_steps: u32) -> HashMap<MapPosition, u32> {
+        let mut dist = HashMap::new();
+        let mut queue = VecDeque::new();
+
+        dist.insert(start, 0);
+        queue.push_back(start);
+
+        while let Some(current) = queue.pop_front() {
+            let d = dist[&current];
+            if d >= max_steps { continue; }
+
+            for neighbor in current.neighbors() {
+                if dist.contains_key(&neighbor) || !map.is_standable(neighbor) { continue; }
+                dist.insert(neighbor, d + 1);
+                queue.push_back(neighbor);
+            }
+        }
+
+        dist.remove(&start);
+        dist
+    }
+}
@@ -0,0 +1,103 @@
+// src/state/modes/game/victory.rs
+
+use serde::{Serialize, Deserialize};
+use crate::state::WorldState;
+use crate::specials::entity::EntityID;
+use crate::map::position::MapPosition;
+
+use super::events::GameEvent;
+
+/// Умова завершення гри. Рушій перевіряє їх по порядку після кожної команди.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum WinCondition {
+    /// Перемагає остання команда з живими юнітами
+    /// (діє лише якщо на старті команд було щонайменше дві).
+    LastTeamStanding,
+    /// Гра завершується смертю цього юніта; перемагає команда вбивці.
+    EliminateUnit { unit: EntityID },
+    /// Команда перемагає, якщо протрималась `turns` повних раундів.
+    SurviveTurns { team: u32, turns: u32 },
+    /// Команда перемагає, щойно її юніт стане в прямокутник `min..=max`.
+    ReachZone { team: u32, min: MapPosition, max: MapPosition },
+}
+
+/// Результат завершеної гри.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct GameOutcome {
+    /// `None` — нічия (наприклад, усі загинули одночасно).
+    pub winner: Option<u32>,
+    /// Раунд, у якому гра завершилась.
+    pub turn: u32,
+    pub condition: WinCondition,
+}
+
+impl WinCondition {
+    /// Короткий опис для UI.
+    pub fn describe(&self) -> String {
+        match self {
+            WinCondition::LastTeamStanding => "Last team standing".to_string(),
+            WinCondition::EliminateUnit { unit } => format!("Eliminate unit #{}", unit),
+            WinCondition::SurviveTurns { team, turns } => format!("Team {} survives {} turns", team, turns),
+            WinCondition::ReachZone { team, min, max } => {
+                format!("Team {} reaches ({},{})-({},{})", team, min.x(), min.y(), max.x(), max.y())
+            }
+        }
+    }
+
+    /// `Some(winner)`, якщо умова спрацювала. `events` — події останньої команди.
+    pub(crate) fn check(&self, world: &WorldState, turn: u32, started_teams: usize, events: &[GameEvent]) -> Option<Option<u32>> {
+        match self {
+            WinCondition::LastTeamStanding => {
+                if started_teams < 2 { return None; }
+                let teams = alive_teams(world);
+                match teams.len() {
+                    0 => Some(None),
+                    1 => Some(Some(teams[0])),
+                    _ => None,
+                }
+            }
+            WinCondition::EliminateUnit { unit } => {
+                let killer = events.iter().find_map(|ev| match ev {
+                    GameEvent::EntityDied { entity, killer } if entity == unit => Some(*killer),
+                    _ => None,
+                })?;
+
+                let killer_team = killer.and_then(|k| world.get_entity(k)).map(|e| e.team());
+                if killer_team.is_some() {
+                    return Some(killer_team);
+                }
+
+                // Без відомого вбивці перемога дістається єдиному суперникові, якщо він один.
+                let victim_team = world.get_entity(*unit).map(|e| e.team());
+                let others: Vec<u32> = alive_teams(world).into_iter()
+                    .filter(|t| Some(*t) != victim_team)
+                    .collect();
+                Some(if others.len() == 1 { Some(others[0]) } else { None })
+            }
+            WinCondition::SurviveTurns { team, turns } => {
+                let alive = world.entities.iter().any(|e| e.team() == *team && !e.is_dead());
+                (alive && turn > *turns).then_some(Some(*team))
+            }
+            WinCondition::ReachZone { team, min, max } => {
+                let reached = world.entities.iter().any(|e| {
+                    let p = e.position();
+                    e.team() == *team && !e.is_dead()
+                        && p.x() >= min.x() && p.x() <= max.x()
+                        && p.y() >= min.y() && p.y() <= max.y()
+                });
+                reached.then_some(Some(*team))
+            }
+        }
+    }
+}
+
+/// Відсортований список команд, у яких є живі юніти.
+pub fn alive_teams(world: &WorldState) -> Vec<u32> {
+    let mut teams: Vec<u32> = world.entities.iter()
+        .filter(|e| !e.is_dead())
+        .map(|e| e.team())
+        .collect();
+    teams.sort();
+    teams.dedup();
+    teams
+}
@@ -2,6 +2,8 @@
 
 pub mod editor;
 pub mod game;  
+pub mod file_picker;
 
 pub use editor::EditorMode;
-pub use game::GameMode;
\ No newline at end of file
+pub use game::GameMode;
+pub use file_picker::{FilePicker, PickerPurpose};
\ No newline at end of file
@@ -0,0 +1,97 @@
+// src/state/save_format.rs
+
+use std::io;
+
+use serde_json::Value;
+
+use crate::specials::skill;
+use crate::specials::status::{StatusEffect, StatusKind};
+use super::world_state::WorldState;
+
+/// Поточна версія формату збереження. Файли без поля версії вважаються версією 0.
+pub const SAVE_VERSION: u64 = 1;
+/// Ключ верхнього рівня з номером версії.
+pub const VERSION_KEY: &str = "version";
+
+type Migration = fn(&mut Value) -> io::Result<()>;
+
+/// Міграції по порядку: `MIGRATIONS[n]` піднімає документ з версії n до n + 1.
+const MIGRATIONS: [Migration; SAVE_VERSION as usize] = [v0_to_v1];
+
+/// Серіалізує світ у документ поточної версії.
+pub fn to_document(world: &WorldState) -> io::Result<Value> {
+    let mut doc = serde_json::to_value(world)?;
+    set_version(&mut doc, SAVE_VERSION)?;
+    Ok(doc)
+}
+
+/// Оновлює документ до поточної версії і розбирає його у світ.
+pub fn from_document(doc: Value) -> io::Result<WorldState> {
+    let mut doc = migrate(doc)?;
+    if let Some(obj) = doc.as_object_mut() {
+        obj.remove(VERSION_KEY);
+    }
+    Ok(serde_json::from_value(doc)?)
+}
+
+/// Проганяє всі потрібні міграції. Новіші за `SAVE_VERSION` файли відхиляються.
+pub fn migrate(mut doc: Value) -> io::Result<Value> {
+    let version = document_version(&doc)?;
+    if version > SAVE_VERSION {
+        return Err(invalid(format!(
+            "save format v{} is newer than supported v{}", version, SAVE_VERSION
+        )));
+    }
+
+    for (from, step) in MIGRATIONS.iter().enumerate().skip(version as usize) {
+        step(&mut doc)?;
+        set_version(&mut doc, from as u64 + 1)?;
+    }
+    Ok(doc)
+}
+
+/// Версія документа (0, якщо поля немає).
+pub fn document_version(doc: &Value) -> io::Result<u64> {
+    let obj = doc.as_object().ok_or_else(|| invalid("save file is not a JSON object".to_string()))?;
+    match obj.get(VERSION_KEY) {
+        None => Ok(0),
+        Some(v) => v.as_u64().ok_or_else(|| invalid(format!("bad '{}' field: {}", VERSION_KEY, v))),
+    }
+}
+
+fn set_version(doc: &mut Value, version: u64) -> io::Result<()> {
+    let obj = doc.as_object_mut().ok_or_else(|| invalid("save file is not a JSON object".to_string()))?;
+    obj.insert(VERSION_KEY.to_string(), Value::from(version));
+    Ok(())
+}
+
+fn invalid(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+// =========================================================================
+//                              MIGRATIONS
+// =========================================================================
+
+/// v0 → v1: `stunned_for_turns` стає статусом Stun; додаються статуси,
+/// заряди Wall Breaker і набір скілів.
+fn v0_to_v1(doc: &mut Value) -> io::Result<()> {
+    let default_skills = serde_json::to_value(skill::default_loadout())?;
+
+    let Some(entities) = doc.get_mut("entities").and_then(Value::as_array_mut) else {
+        return Ok(());
+    };
+    for entity in entities.iter_mut().filter_map(Value::as_object_mut) {
+        let stunned = entity.remove("stunned_for_turns").and_then(|v| v.as_u64()).unwrap_or(0);
+        if !entity.contains_key("statuses") {
+            let statuses: Vec<StatusEffect> = match stunned {
+                0 => Vec::new(),
+                turns => vec![StatusEffect::new(StatusKind::Stun, turns as u32, 0)],
+            };
+            entity.insert("statuses".to_string(), serde_json::to_value(statuses)?);
+        }
+        entity.entry("wall_breaker_charges").or_insert(Value::from(0));
+        entity.entry("skills").or_insert_with(|| default_skills.clone());
+    }
+    Ok(())
+}
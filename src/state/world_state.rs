@@ -4,42 +4,86 @@ use crate::map::map::Map;
 use crate::map::position::MapPosition;
 use crate::specials::entity::{Entity, EntityID};
 use crate::specials::powerup::PowerupType;
-use crate::map::tile::TileType; // Не забудь цей імпорт!
+use crate::map::tile::{Tile, TileType}; // Не забудь цей імпорт!
+use crate::state::modes::game::WinCondition;
 use serde::{Serialize, Deserialize};
 use std::fs::File;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::io::{BufReader, BufWriter};
+use super::save_format;
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct WorldState {
     pub map: Map, 
     pub entities: Vec<Entity>, 
     pub next_entity_id: EntityID,
     pub current_team_turn: u32,
+    /// Умови перемоги цієї карти; порожньо — `LastTeamStanding`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub win_conditions: Vec<WinCondition>,
 }
 
+/// Стан однієї клітинки: тайл (якщо в межах карти) і всі сутності на ній.
+/// Використовується історією редактора, щоб зберігати лише змінені клітинки.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellSnapshot {
+    pub pos: MapPosition,
+    pub tile: Option<Tile>,
+    pub entities: Vec<Entity>,
+}
+
+/// Що сталося з сутностями, які опинились поза картою після `resize`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResizeReport {
+    pub relocated: Vec<EntityID>,
+    pub removed: Vec<EntityID>,
+}
+
+impl Default for WorldState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Файл, куди редактор зберігає карту і звідки її підхоплює "Load Latest".
+pub const DEFAULT_MAP_PATH: &str = "map.json";
+/// Розмір карти за замовчуванням.
+pub const DEFAULT_MAP_WIDTH: i32 = 100;
+pub const DEFAULT_MAP_HEIGHT: i32 = 25;
+
 impl WorldState {
     pub fn new() -> Self {
+        Self::with_size(DEFAULT_MAP_WIDTH, DEFAULT_MAP_HEIGHT)
+    }
+
+    /// Порожній світ із картою заданого розміру.
+    pub fn with_size(width: i32, height: i32) -> Self {
         WorldState {
-            map: Map::new(100, 25),
+            map: Map::new(width, height),
             entities: Vec::new(),
             next_entity_id: 0,
             current_team_turn: 1,
+            win_conditions: Vec::new(),
         }
     }
 
     // --- Persistence ---
+
+    /// Зберігає світ у форматі поточної версії (`save_format::SAVE_VERSION`).
     pub fn save(&self, filename: &str) -> std::io::Result<()> {
+        let doc = save_format::to_document(self)?;
         let file = File::create(filename)?;
         let writer = BufWriter::new(file);
-        serde_json::to_writer(writer, self)?;
+        serde_json::to_writer(writer, &doc)?;
         Ok(())
     }
 
+    /// Завантажує світ; старі версії файлу спершу проходять міграції.
     pub fn load(filename: &str) -> std::io::Result<Self> {
         let file = File::open(filename)?;
         let reader = BufReader::new(file);
-        let state = serde_json::from_reader(reader)?;
-        Ok(state)
+        let doc: serde_json::Value = serde_json::from_reader(reader)?;
+        save_format::from_document(doc)
     }
 
     // --- Logic Helpers ---
@@ -68,6 +112,7 @@ impl WorldState {
     }
 
     // Зручний хелпер для спавну (використовується в меню)
+    #[allow(clippy::too_many_arguments)]
     pub fn spawn_entity(
         &mut self, 
         pos: MapPosition, 
@@ -104,6 +149,78 @@ impl WorldState {
         Some(self.add_entity(new_entity))
     }
 
+    /// Переставляє сутність на іншу клітинку, синхронізуючи тайли.
+    /// Повертає false, якщо сутності немає або клітинка зайнята.
+    pub fn relocate_entity(&mut self, id: EntityID, pos: MapPosition) -> bool {
+        if !self.map.is_standable(pos) { return false; }
+
+        let old_pos = match self.get_entity_mut(id) {
+            Some(e) => {
+                let old = e.position();
+                e.set_position(pos);
+                old
+            }
+            None => return false,
+        };
+
+        if let Some(tile) = self.map.get_tile_mut(old_pos) {
+            tile.set_entity(None);
+        }
+        if let Some(tile) = self.map.get_tile_mut(pos) {
+            tile.set_entity(Some(id));
+        }
+        true
+    }
+
+    /// Змінює розмір карти. Сутності, що випали за межі, переносяться на
+    /// найближчу вільну клітинку, а якщо такої немає — видаляються.
+    pub fn resize(&mut self, width: i32, height: i32) -> ResizeReport {
+        self.map.resize(width, height);
+
+        let outside: Vec<(EntityID, MapPosition)> = self.entities.iter()
+            .filter(|e| !self.map.in_bounds(e.position()))
+            .map(|e| (e.id(), e.position()))
+            .collect();
+
+        let mut report = ResizeReport::default();
+        for (id, pos) in outside {
+            let clamped = MapPosition::new(pos.x().clamp(0, width - 1), pos.y().clamp(0, height - 1));
+            match self.nearest_standable(clamped) {
+                Some(free) => {
+                    if let Some(e) = self.get_entity_mut(id) {
+                        e.set_position(free);
+                    }
+                    if let Some(tile) = self.map.get_tile_mut(free) {
+                        tile.set_entity(Some(id));
+                    }
+                    report.relocated.push(id);
+                }
+                None => {
+                    self.entities.retain(|e| e.id() != id);
+                    report.removed.push(id);
+                }
+            }
+        }
+        report
+    }
+
+    /// Найближча (BFS по карті, крізь стіни теж) клітинка, де можна стати.
+    fn nearest_standable(&self, from: MapPosition) -> Option<MapPosition> {
+        let mut seen = HashSet::from([from]);
+        let mut queue = VecDeque::from([from]);
+        while let Some(pos) = queue.pop_front() {
+            if self.map.is_standable(pos) {
+                return Some(pos);
+            }
+            for n in pos.neighbors() {
+                if self.map.in_bounds(n) && seen.insert(n) {
+                    queue.push_back(n);
+                }
+            }
+        }
+        None
+    }
+
     pub fn build_wall(&mut self, pos: MapPosition) -> bool {
         self.clear_pos(pos);
         self.map.build_wall(pos)
@@ -116,6 +233,44 @@ impl WorldState {
         }
     }
 
+    /// Кладе паверап на вільну прохідну клітинку.
+    pub fn place_powerup(&mut self, pos: MapPosition, powerup: PowerupType) -> bool {
+        if !self.map.is_standable(pos) { return false; }
+        if let Some(tile) = self.map.get_tile_mut(pos) {
+            tile.set_powerup(powerup);
+            return true;
+        }
+        false
+    }
+
+    pub fn snapshot_cell(&self, pos: MapPosition) -> CellSnapshot {
+        CellSnapshot {
+            pos,
+            tile: self.map.get_tile(pos).copied(),
+            entities: self.entities.iter().filter(|e| e.position() == pos).cloned().collect(),
+        }
+    }
+
+    /// Повертає клітинку до знімка: тайл і сутності на ній.
+    pub fn restore_cell(&mut self, snapshot: &CellSnapshot) {
+        self.entities.retain(|e| e.position() != snapshot.pos);
+        self.entities.extend(snapshot.entities.iter().cloned());
+        if let (Some(tile), Some(saved)) = (self.map.get_tile_mut(snapshot.pos), snapshot.tile) {
+            *tile = saved;
+        }
+    }
+
+    /// ID сутностей у порядку зберігання (від нього залежить черговість ходів AI).
+    pub fn entity_order(&self) -> Vec<EntityID> {
+        self.entities.iter().map(|e| e.id()).collect()
+    }
+
+    /// Розставляє сутності в порядку `order`; відсутні в ньому йдуть у кінець.
+    pub fn reorder_entities(&mut self, order: &[EntityID]) {
+        let rank: HashMap<EntityID, usize> = order.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+        self.entities.sort_by_key(|e| rank.get(&e.id()).copied().unwrap_or(usize::MAX));
+    }
+
     fn clear_pos(&mut self, pos: MapPosition) {
         // Спочатку знаходимо ID того, кого треба видалити
         let id_to_remove = self.map.get_tile(pos).and_then(|t| t.entity_id());
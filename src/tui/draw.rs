@@ -1,6 +1,6 @@
 use ratatui::{
     Frame, 
-    widgets::{Paragraph, Block, Borders, Wrap},
+    widgets::{Paragraph, Block, Borders, Wrap, Clear},
     style::{Style, Color, Modifier}, 
     layout::Rect,
     prelude::Alignment,
@@ -10,18 +10,32 @@ use ratatui::{
 use crate::state::application_state::{ApplicationState, AppState};
 use crate::state::world_state::WorldState;
 use crate::map::tile::TileType;
+use crate::map::position::MapPosition;
+use crate::state::modes::game::{GameMode, GameOutcome};
 
 // Імпорти з наших нових чистих модулів
-use crate::tui::layout::{get_main_layout, get_centered_rect};
-use crate::tui::utils::get_team_color;
+use crate::tui::layout::{get_main_layout, get_centered_rect, get_map_viewport};
+use crate::tui::layout::{get_picker_rect, get_picker_visible_rows, PICKER_LIST_ROW};
+use crate::state::modes::{FilePicker, PickerPurpose};
+use crate::state::modes::editor::editor_mode::EditorDialog;
+use crate::state::camera::Camera;
+use crate::tui::overlay::MapOverlay;
+use crate::tui::utils::{get_team_color, get_powerup_color};
 use crate::tui::menu::MenuState; 
+use crate::state::form::Form;
+use crate::tui::form::field_line;
 
 // =========================================================================
 //                            MAP RENDERING
 // =========================================================================
 
-fn draw_map_tiles(f: &mut Frame, world_state: &WorldState, inner_area: Rect) {
+fn draw_map_tiles(f: &mut Frame, world_state: &WorldState, map_area: Rect, camera: &Camera, cursor: MapPosition, overlay: &MapOverlay) {
     let map = &world_state.map;
+    let offset = camera.offset();
+
+    let title = format!(" MAP ({}, {}) | CURSOR ({}, {}) ", offset.x(), offset.y(), cursor.x(), cursor.y());
+    f.render_widget(Block::default().title(title).borders(Borders::ALL), map_area);
+    let inner_area = get_map_viewport(map_area);
     
     // Оптимізація: Малюємо тільки те, що влазить у вюпорт
     let view_width = inner_area.width as i32;
@@ -32,7 +46,8 @@ fn draw_map_tiles(f: &mut Frame, world_state: &WorldState, inner_area: Rect) {
     for y in 0..view_height {
         for x in 0..view_width {
             // Використовуємо наш safe accessor
-            let tile = match map.get_tile((x, y)) {
+            let world_pos = camera.screen_to_world(x, y);
+            let tile = match map.get_tile(world_pos) {
                 Some(t) => t,
                 None => continue,
             };
@@ -42,6 +57,12 @@ fn draw_map_tiles(f: &mut Frame, world_state: &WorldState, inner_area: Rect) {
                 TileType::Wall => (tile.symbol(), Style::default().fg(Color::Rgb(255, 165, 0))),
             };
 
+            let powerup = tile.powerup();
+            if powerup.is_some() {
+                symbol = powerup.symbol();
+                style = Style::default().fg(get_powerup_color(powerup)).add_modifier(Modifier::BOLD);
+            }
+
             if let Some(id) = tile.entity_id() { 
                 if let Some(e) = world_state.get_entity(id) {
                     symbol = e.symbol(); 
@@ -54,6 +75,14 @@ fn draw_map_tiles(f: &mut Frame, world_state: &WorldState, inner_area: Rect) {
                 }
             }
 
+            if let Some(bg) = overlay.background(world_pos) {
+                style = style.bg(bg);
+            }
+
+            if world_pos == cursor {
+                style = style.add_modifier(Modifier::REVERSED);
+            }
+
             // Безпечний запис у буфер
             let bx = inner_area.x + x as u16;
             let by = inner_area.y + y as u16;
@@ -68,6 +97,30 @@ fn draw_map_tiles(f: &mut Frame, world_state: &WorldState, inner_area: Rect) {
     }
 }
 
+/// Підсвітка для гри: карта загроз, зона досяжності й шлях для Move,
+/// дальність і цілі для Attack.
+fn build_game_overlay(mode: &GameMode) -> MapOverlay {
+    let mut overlay = MapOverlay::new();
+
+    if let Some(danger) = mode.danger_map() {
+        overlay.highlight_all(danger.iter().copied(), Color::Rgb(60, 0, 45));
+    }
+
+    if let Some(preview) = mode.move_preview() {
+        overlay.highlight_all(preview.reachable, Color::Rgb(0, 40, 90));
+        if let Some((path, _)) = preview.path {
+            overlay.highlight_all(path, Color::Rgb(0, 110, 160));
+        }
+    }
+
+    if let Some(preview) = mode.attack_preview() {
+        overlay.highlight_all(preview.in_range, Color::Rgb(70, 20, 20));
+        overlay.highlight_all(preview.targets, Color::Rgb(170, 30, 30));
+    }
+
+    overlay
+}
+
 // =========================================================================
 //                        GENERIC MENU RENDERER
 // =========================================================================
@@ -115,7 +168,7 @@ fn draw_generic_menu<T: MenuState>(f: &mut Frame, state: &T, area: Rect) {
     lines.push(Line::from(""));
     lines.push(Line::from(""));
     lines.push(Line::from(Span::styled("--- LOGS ---", Style::default().add_modifier(Modifier::DIM))));
-    lines.push(Line::from(Span::raw(state.get_logs())));
+    lines.extend(state.get_logs());
 
     let p = Paragraph::new(lines)
         .wrap(Wrap { trim: false })
@@ -128,42 +181,154 @@ fn draw_generic_menu<T: MenuState>(f: &mut Frame, state: &T, area: Rect) {
 //                            MAIN ENTRY POINTS
 // =========================================================================
 
-fn draw_greeting_menu(f: &mut Frame){
+fn draw_greeting_menu(f: &mut Frame, message: Option<&str>){
     let menu_area = get_centered_rect(40, 40, f.area());
     
-    let menu_lines = vec![
+    let mut menu_lines = vec![
         Line::from(Span::styled("--- RUST TUI ROGUELIKE ---", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))), 
         Line::from(""), 
         Line::from("Controls: Mouse to click, Keys shortcuts supported"), 
         Line::from(""), 
         Line::from(Span::styled("[1] EDITOR MODE", Style::default().fg(Color::Cyan))), 
         Line::from("[2] NEW GAME"), 
-        Line::from("[3] LOAD LATEST"), 
+        Line::from("[3] LOAD MAP..."), 
+        Line::from(Span::styled("[4] EDIT MAP...", Style::default().fg(Color::Cyan))), 
         Line::from(""), 
         Line::from(Span::styled("[Q] QUIT", Style::default().fg(Color::Red))), 
     ];
+    if let Some(message) = message {
+        menu_lines.push(Line::from(""));
+        menu_lines.push(Line::from(Span::styled(message.to_string(), Style::default().fg(Color::Red))));
+    }
     let p = Paragraph::new(menu_lines)
         .alignment(Alignment::Center)
         .block(Block::default().title(" Main Menu ").borders(Borders::ALL));
     f.render_widget(p, menu_area);
 }
 
+/// Вікно вибору файлу карти з теки карт.
+fn draw_file_picker(f: &mut Frame, picker: &FilePicker) {
+    let area = get_picker_rect(f.area());
+    let visible = get_picker_visible_rows(area);
+    let offset = picker.scroll_offset(visible);
+
+    let mut lines = vec![
+        Line::from(Span::styled(format!("Folder: {}", picker.dir().display()), Style::default().fg(Color::DarkGray))),
+        Line::from(""),
+    ];
+    for (i, name) in picker.files().iter().enumerate().skip(offset).take(visible) {
+        let style = if i == picker.selected() {
+            Style::default().fg(Color::Black).bg(Color::Cyan).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        lines.push(Line::from(Span::styled(format!(" {} ", name), style)));
+    }
+    while lines.len() < PICKER_LIST_ROW as usize - 1 + visible {
+        lines.push(Line::from(""));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(match picker.error() {
+        Some(error) => Line::from(Span::styled(error.to_string(), Style::default().fg(Color::Red))),
+        None => Line::from(""),
+    });
+    lines.push(Line::from(Span::styled("[Enter] Open  [Esc] Back", Style::default().fg(Color::DarkGray))));
+
+    let title = match picker.purpose() {
+        PickerPurpose::Play => " Load Map ",
+        PickerPurpose::Edit => " Edit Map ",
+    };
+    let p = Paragraph::new(lines)
+        .block(Block::default().title(title).borders(Borders::ALL));
+    f.render_widget(Clear, area);
+    f.render_widget(p, area);
+}
+
+/// Модальне вікно з формою (діалоги редактора).
+fn draw_form_dialog<K: Copy + Eq>(f: &mut Frame, title: &str, form: &Form<K>, notice: Option<String>) {
+    let area = get_centered_rect(30, 25, f.area());
+
+    let mut lines = vec![Line::from("")];
+    lines.extend(form.fields().iter().filter_map(|field| field_line(form, field.key())));
+    lines.push(Line::from(""));
+    if let Some(notice) = notice {
+        lines.push(Line::from(Span::styled(notice, Style::default().fg(Color::Yellow))));
+    }
+    if let Some(error) = form.focused().and_then(|field| field.error()) {
+        lines.push(Line::from(Span::styled(error.to_string(), Style::default().fg(Color::Red))));
+    }
+    lines.push(Line::from(Span::styled("[Enter] OK  [Esc] Cancel", Style::default().fg(Color::DarkGray))));
+
+    let p = Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .block(Block::default().title(title.to_string()).borders(Borders::ALL));
+    f.render_widget(Clear, area);
+    f.render_widget(p, area);
+}
+
+/// Модальне вікно кінця гри поверх карти.
+fn draw_game_over(f: &mut Frame, outcome: &GameOutcome) {
+    let area = get_centered_rect(40, 30, f.area());
+
+    let (verdict, color) = match outcome.winner {
+        Some(team) => (format!("TEAM {} WINS!", team), get_team_color(team)),
+        None => ("DRAW".to_string(), Color::White),
+    };
+
+    let lines = vec![
+        Line::from(Span::styled("--- GAME OVER ---", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))),
+        Line::from(""),
+        Line::from(Span::styled(verdict, Style::default().fg(color).add_modifier(Modifier::BOLD))),
+        Line::from(format!("Turns played: {}", outcome.turn)),
+        Line::from(outcome.condition.describe()),
+        Line::from(""),
+        Line::from(Span::styled("[E] Export Log", Style::default().fg(Color::Cyan))),
+        Line::from(Span::styled("[Q] Back to Menu", Style::default().fg(Color::Red))),
+    ];
+
+    let p = Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .block(Block::default().title(" Result ").borders(Borders::ALL));
+    f.render_widget(Clear, area);
+    f.render_widget(p, area);
+}
+
 pub fn ui(f: &mut Frame, app_state: &ApplicationState) {
      let size = f.area();
      
      match &app_state.state {
         AppState::Editor(mode) => {
             let (map_area, menu_area) = get_main_layout(size);
-            draw_map_tiles(f, &mode.world(), map_area); 
+            let mut overlay = MapOverlay::new();
+            overlay.highlight_all(mode.stroke_preview(), Color::DarkGray);
+            if let Some(unit) = mode.selected_unit() {
+                overlay.highlight(unit.position(), Color::Yellow);
+            }
+            draw_map_tiles(f, mode.world(), map_area, mode.camera(), mode.cursor(), &overlay); 
             draw_generic_menu(f, mode, menu_area);
+
+            match mode.dialog() {
+                Some(EditorDialog::MapSize(dialog)) => draw_form_dialog(f, dialog.title(), dialog.form(), None),
+                Some(EditorDialog::SaveAs(dialog)) => draw_form_dialog(f, dialog.title(), dialog.form(), dialog.notice()),
+                None => {}
+            }
         },
         AppState::Game(mode) => {
             let (map_area, menu_area) = get_main_layout(size);
-            draw_map_tiles(f, mode.world(), map_area); 
+            let overlay = build_game_overlay(mode);
+            draw_map_tiles(f, mode.world(), map_area, mode.camera(), mode.cursor(), &overlay); 
             draw_generic_menu(f, mode, menu_area);
+
+            if let Some(outcome) = mode.outcome() {
+                draw_game_over(f, outcome);
+            }
         },
         AppState::Menu => {
-             draw_greeting_menu(f);
+             draw_greeting_menu(f, app_state.message.as_deref());
+        }
+        AppState::FilePicker(picker) => {
+             draw_file_picker(f, picker);
         }
         _ =>{}
      }
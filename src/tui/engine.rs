@@ -4,10 +4,14 @@ use crossterm::terminal::{enable_raw_mode, disable_raw_mode, EnterAlternateScree
 use crossterm::execute;
 use color_eyre::Result;
 
+use ratatui::layout::Rect;
+
 use crate::state::application_state::{ApplicationState, AppState};
+use crate::state::actions::Action;
 // Make sure to import GameMode if not impli
 use super::input::handle_input;
 use super::draw::ui;
+use super::layout::{get_main_layout, get_map_viewport};
 
 pub fn run() -> Result<()> {
     let mut app = ApplicationState::new();
@@ -29,6 +33,12 @@ pub fn run() -> Result<()> {
             mode.tick();
         }
 
+        // Камера має знати, скільки клітинок зараз видно
+        let size = terminal.size()?;
+        let (map_rect, _) = get_main_layout(Rect::new(0, 0, size.width, size.height));
+        let view = get_map_viewport(map_rect);
+        app.apply_action(Action::ResizeViewport { width: view.width as i32, height: view.height as i32 });
+
         // 3. RENDER PHASE
         terminal.draw(|f| ui(f, &app))?;
     }
@@ -0,0 +1,35 @@
+// src/tui/form.rs
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+
+use crate::state::form::Form;
+
+/// Рядок поля для меню: курсор — інверсний символ, помилка — червоним.
+pub fn field_line<K: Copy + Eq>(form: &Form<K>, key: K) -> Option<Line<'static>> {
+    let field = form.field(key)?;
+    let focused = form.is_focused(key);
+
+    let base = if field.error().is_some() {
+        Style::default().fg(Color::Red)
+    } else if focused {
+        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default()
+    };
+
+    let mut spans = vec![Span::styled(format!("{}: [", field.label()), base)];
+    if focused {
+        let chars: Vec<char> = field.buffer().chars().collect();
+        let before: String = chars[..field.cursor()].iter().collect();
+        let at = chars.get(field.cursor()).map_or(" ".to_string(), |c| c.to_string());
+        let after: String = chars.iter().skip(field.cursor() + 1).collect();
+        spans.push(Span::styled(before, base));
+        spans.push(Span::styled(at, base.add_modifier(Modifier::REVERSED)));
+        spans.push(Span::styled(after, base));
+    } else {
+        spans.push(Span::styled(field.buffer().to_string(), base));
+    }
+    spans.push(Span::styled("]", base));
+    Some(Line::from(spans))
+}
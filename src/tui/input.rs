@@ -1,18 +1,22 @@
 // src/tui/input.rs
 
-use crossterm::event::{self, Event, KeyCode, MouseEventKind, MouseButton};
+use crossterm::event::{self, Event, KeyCode, KeyModifiers, MouseEventKind, MouseButton};
 use crossterm::terminal; 
 use std::time::Duration;
 use ratatui::layout::Rect; 
 use color_eyre::Result;
 
-use crate::state::actions::{Action, MenuSelection};
+use crate::state::actions::{Action, FormKey, MenuSelection};
 use crate::state::application_state::{ApplicationState, AppState};
-use crate::state::modes::editor::input::InputTarget; 
-use crate::map::position::MapPosition;
 
 // Імпортуємо наш Single Source of Truth
-use crate::tui::layout::{get_main_layout, get_centered_rect, is_point_in_rect};
+use crate::tui::layout::{get_main_layout, get_centered_rect, get_map_viewport, is_point_in_rect};
+use crate::tui::layout::{get_picker_rect, get_picker_visible_rows, PICKER_LIST_ROW};
+use crate::state::camera::SCROLL_STEP;
+use crate::map::position::MapPosition;
+
+/// На скільки записів гортається журнал за PageUp/PageDown.
+const LOG_PAGE: i32 = 5;
 
 pub fn handle_input(app: &ApplicationState) -> Result<Option<Action>> {
     if event::poll(Duration::from_millis(16))? {
@@ -24,10 +28,17 @@ pub fn handle_input(app: &ApplicationState) -> Result<Option<Action>> {
                 // ... (Твій код клавіатури ідеальний, залишаємо без змін) ...
                 // 1. Editor Typing
                 if let AppState::Editor(mode) = &app.state {
-                    if mode.input_target() != InputTarget::None { // Using getter now!
+                    if mode.is_typing() {
                         match key.code {
-                            KeyCode::Char(c) if c.is_digit(10) => return Ok(Some(Action::EditorType(c))),
-                            KeyCode::Backspace => return Ok(Some(Action::EditorBackspace)),
+                            KeyCode::Char(c) => return Ok(Some(Action::EditorFormKey(FormKey::Char(c)))),
+                            KeyCode::Backspace => return Ok(Some(Action::EditorFormKey(FormKey::Backspace))),
+                            KeyCode::Delete => return Ok(Some(Action::EditorFormKey(FormKey::Delete))),
+                            KeyCode::Left => return Ok(Some(Action::EditorFormKey(FormKey::Left))),
+                            KeyCode::Right => return Ok(Some(Action::EditorFormKey(FormKey::Right))),
+                            KeyCode::Home => return Ok(Some(Action::EditorFormKey(FormKey::Home))),
+                            KeyCode::End => return Ok(Some(Action::EditorFormKey(FormKey::End))),
+                            KeyCode::Tab | KeyCode::Down => return Ok(Some(Action::EditorFormKey(FormKey::NextField))),
+                            KeyCode::BackTab | KeyCode::Up => return Ok(Some(Action::EditorFormKey(FormKey::PrevField))),
                             KeyCode::Enter => return Ok(Some(Action::EditorConfirm)),
                             KeyCode::Esc => return Ok(Some(Action::EditorCancel)),
                             _ => return Ok(None), 
@@ -35,26 +46,60 @@ pub fn handle_input(app: &ApplicationState) -> Result<Option<Action>> {
                     }
                 }
 
-                // 2. Shortcuts
+                // 2. Cursor & Camera (стрілки рухають курсор, Shift+стрілки — камеру)
+                if matches!(app.state, AppState::Editor(_) | AppState::Game(_)) {
+                    let dir = match key.code {
+                        KeyCode::Up => Some((0, -1)),
+                        KeyCode::Down => Some((0, 1)),
+                        KeyCode::Left => Some((-1, 0)),
+                        KeyCode::Right => Some((1, 0)),
+                        _ => None,
+                    };
+                    if let Some((dx, dy)) = dir {
+                        if key.modifiers.contains(KeyModifiers::SHIFT) {
+                            return Ok(Some(Action::ScrollCamera { dx: dx * SCROLL_STEP, dy: dy * SCROLL_STEP }));
+                        }
+                        return Ok(Some(Action::MoveCursor { dx, dy }));
+                    }
+                    if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
+                        return Ok(Some(Action::CursorActivate));
+                    }
+                }
+
+                // 3. Shortcuts
                 match app.state {
                     AppState::Menu => {
                         match key.code {
                             KeyCode::Char('1') => return Ok(Some(Action::MenuSelect(MenuSelection::EnterBuildMode))),
                             KeyCode::Char('2') => return Ok(Some(Action::MenuSelect(MenuSelection::EnterPlayMode))),
-                            KeyCode::Char('3') => return Ok(Some(Action::MenuSelect(MenuSelection::LoadLatest))),
+                            KeyCode::Char('3') => return Ok(Some(Action::MenuSelect(MenuSelection::LoadMap))),
+                            KeyCode::Char('4') => return Ok(Some(Action::MenuSelect(MenuSelection::EditExisting))),
                             KeyCode::Char('q') | KeyCode::Char('Q') => return Ok(Some(Action::QuitApp)),
                             _ => {}
                         }
                     },
+                    AppState::FilePicker(_) => {
+                        match key.code {
+                            KeyCode::Up | KeyCode::Char('k') => return Ok(Some(Action::PickerMove { delta: -1 })),
+                            KeyCode::Down | KeyCode::Char('j') => return Ok(Some(Action::PickerMove { delta: 1 })),
+                            KeyCode::Enter => return Ok(Some(Action::PickerConfirm)),
+                            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('Q') => return Ok(Some(Action::BackToMenu)),
+                            _ => {}
+                        }
+                    },
                     AppState::Editor(_) => {
                         match key.code {
                             KeyCode::Tab => return Ok(Some(Action::CycleBuildTool)),
+                            KeyCode::Char('z') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(Some(Action::EditorUndo)),
+                            KeyCode::Char('y') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(Some(Action::EditorRedo)),
                             KeyCode::Char(c) => return Ok(Some(Action::EditorKeyPress(c))),
                             _ => {}
                         }
                     },
                     AppState::Game(_) => {
                         match key.code {
+                            KeyCode::PageUp => return Ok(Some(Action::ScrollLog { delta: LOG_PAGE })),
+                            KeyCode::PageDown => return Ok(Some(Action::ScrollLog { delta: -LOG_PAGE })),
                             KeyCode::Char(c) => return Ok(Some(Action::GameKeyPress(c))),
                             _ => {}
                         }
@@ -65,6 +110,68 @@ pub fn handle_input(app: &ApplicationState) -> Result<Option<Action>> {
             
             // --- MOUSE ---
             Event::Mouse(mouse) => {
+                // Мишка біля краю карти прокручує камеру
+                if let MouseEventKind::Moved = mouse.kind {
+                    if !matches!(app.state, AppState::Editor(_) | AppState::Game(_)) {
+                        return Ok(None);
+                    }
+                    let (term_width, term_height) = terminal::size()?;
+                    let (map_rect, _) = get_main_layout(Rect::new(0, 0, term_width, term_height));
+                    let view = get_map_viewport(map_rect);
+                    let (x, y) = (mouse.column as i32, mouse.row as i32);
+
+                    if !is_point_in_rect(x, y, map_rect) {
+                        return Ok(None);
+                    }
+                    let dx = if x <= view.x as i32 { -1 } else if x >= (view.x + view.width) as i32 - 1 { 1 } else { 0 };
+                    let dy = if y <= view.y as i32 { -1 } else if y >= (view.y + view.height) as i32 - 1 { 1 } else { 0 };
+                    if dx != 0 || dy != 0 {
+                        return Ok(Some(Action::ScrollCamera { dx, dy }));
+                    }
+                    return Ok(None);
+                }
+
+                // Колесо миші над боковим меню гортає журнал гри
+                if let MouseEventKind::ScrollUp | MouseEventKind::ScrollDown = mouse.kind {
+                    if let AppState::Game(_) = app.state {
+                        let (term_width, term_height) = terminal::size()?;
+                        let (_, menu_rect) = get_main_layout(Rect::new(0, 0, term_width, term_height));
+                        if is_point_in_rect(mouse.column as i32, mouse.row as i32, menu_rect) {
+                            let delta = if let MouseEventKind::ScrollUp = mouse.kind { 1 } else { -1 };
+                            return Ok(Some(Action::ScrollLog { delta }));
+                        }
+                    }
+                    return Ok(None);
+                }
+
+                // Перетягування пензлем у редакторі (позиція рахується навіть поза вюпортом)
+                if let MouseEventKind::Drag(MouseButton::Left) | MouseEventKind::Up(MouseButton::Left) = mouse.kind {
+                    if let AppState::Editor(mode) = &app.state {
+                        let (term_width, term_height) = terminal::size()?;
+                        let (map_rect, _) = get_main_layout(Rect::new(0, 0, term_width, term_height));
+                        let view = get_map_viewport(map_rect);
+                        let pos = mode.camera().screen_to_world(
+                            mouse.column as i32 - view.x as i32,
+                            mouse.row as i32 - view.y as i32,
+                        );
+                        let map = &mode.world().map;
+                        return match mouse.kind {
+                            // Поза картою (в'юпорт буває більшим за малу карту) мазок не продовжуємо
+                            MouseEventKind::Drag(_) if map.get_tile(pos).is_none() => Ok(None),
+                            MouseEventKind::Drag(_) => Ok(Some(Action::EditorStrokeMove { pos })),
+                            // Відпускання за краєм завершує мазок на найближчій клітинці карти
+                            _ => {
+                                let pos = MapPosition::new(
+                                    pos.x().clamp(0, map.width() - 1),
+                                    pos.y().clamp(0, map.height() - 1),
+                                );
+                                Ok(Some(Action::EditorStrokeEnd { pos }))
+                            }
+                        };
+                    }
+                    return Ok(None);
+                }
+
                 if let MouseEventKind::Down(MouseButton::Left) = mouse.kind {
                     let x = mouse.column as i32;
                     let y = mouse.row as i32;
@@ -83,17 +190,30 @@ pub fn handle_input(app: &ApplicationState) -> Result<Option<Action>> {
                                 match relative_y {
                                     5 => return Ok(Some(Action::MenuSelect(MenuSelection::EnterBuildMode))),
                                     6 => return Ok(Some(Action::MenuSelect(MenuSelection::EnterPlayMode))),
-                                    7 => return Ok(Some(Action::MenuSelect(MenuSelection::LoadLatest))),
-                                    9 => return Ok(Some(Action::QuitApp)),
+                                    7 => return Ok(Some(Action::MenuSelect(MenuSelection::LoadMap))),
+                                    8 => return Ok(Some(Action::MenuSelect(MenuSelection::EditExisting))),
+                                    10 => return Ok(Some(Action::QuitApp)),
                                     _ => {}
                                 }
                             }
                         },
 
-                        // 2. EDITOR & GAME (Unified Logic)
+                        // 2. FILE PICKER (клік по файлу відкриває його)
+                        AppState::FilePicker(picker) => {
+                            let rect = get_picker_rect(screen_area);
+                            let row = y - (rect.y + PICKER_LIST_ROW) as i32;
+                            let visible = get_picker_visible_rows(rect);
+                            if is_point_in_rect(x, y, rect) && row >= 0 && (row as usize) < visible {
+                                let index = picker.scroll_offset(visible) + row as usize;
+                                return Ok(Some(Action::PickerSelect { index }));
+                            }
+                        },
+
+                        // 3. EDITOR & GAME (Unified Logic)
                         AppState::Editor(_) | AppState::Game(_) => {
                             // Отримуємо ті самі прямокутники, що і Draw!
                             let (map_rect, menu_rect) = get_main_layout(screen_area);
+                            let view = get_map_viewport(map_rect);
 
                             // А) Клік по МЕНЮ (Сайдбар)
                             if is_point_in_rect(x, y, menu_rect) {
@@ -107,15 +227,21 @@ pub fn handle_input(app: &ApplicationState) -> Result<Option<Action>> {
                                 }
                             }
                             
-                            // Б) Клік по КАРТІ
-                            else if is_point_in_rect(x, y, map_rect) {
-                                // Рахуємо координати відносно карти (якщо карта не з 0,0)
-                                let map_x = x - map_rect.x as i32;
-                                let map_y = y - map_rect.y as i32;
-                                let pos = MapPosition::new(map_x, map_y);
+                            // Б) Клік по КАРТІ (всередині рамки, з урахуванням зсуву камери)
+                            else if is_point_in_rect(x, y, view) {
+                                let (camera, world) = match &app.state {
+                                    AppState::Editor(mode) => (mode.camera(), mode.world()),
+                                    AppState::Game(mode) => (mode.camera(), mode.world()),
+                                    _ => unreachable!(),
+                                };
+                                let pos = camera.screen_to_world(x - view.x as i32, y - view.y as i32);
+                                // Порожнє місце в'юпорта за краєм карти — не клітинка
+                                if world.map.get_tile(pos).is_none() {
+                                    return Ok(None);
+                                }
 
                                 match app.state {
-                                    AppState::Editor(_) => return Ok(Some(Action::EditorClick { pos })),
+                                    AppState::Editor(_) => return Ok(Some(Action::EditorStrokeStart { pos })),
                                     AppState::Game(_) => return Ok(Some(Action::GameClick { pos })),
                                     _ => unreachable!(),
                                 }
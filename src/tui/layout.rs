@@ -1,4 +1,4 @@
-use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::layout::{Constraint, Direction, Layout, Margin, Rect};
 
 // Константи пропорцій (75% карта, 25% меню)
 pub const MAP_PERCENTAGE: u16 = 75;
@@ -16,6 +16,11 @@ pub fn get_main_layout(area: Rect) -> (Rect, Rect) {
     (chunks[0], chunks[1])
 }
 
+/// Внутрішня частина панелі карти (без рамки) — саме тут малюються тайли.
+pub fn get_map_viewport(map_area: Rect) -> Rect {
+    map_area.inner(Margin { horizontal: 1, vertical: 1 })
+}
+
 /// Центрує прямокутник (для головного меню або модальних вікон).
 pub fn get_centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
@@ -43,4 +48,17 @@ pub fn is_point_in_rect(x: i32, y: i32, rect: Rect) -> bool {
     x < (rect.x + rect.width) as i32 &&
     y >= rect.y as i32 && 
     y < (rect.y + rect.height) as i32
-}
\ No newline at end of file
+}
+
+/// Рядок (від верхньої рамки), з якого у вікні вибору файлу починається список.
+pub const PICKER_LIST_ROW: u16 = 3;
+
+/// Вікно вибору файлу карти.
+pub fn get_picker_rect(area: Rect) -> Rect {
+    get_centered_rect(50, 60, area)
+}
+
+/// Скільки файлів вміщує список (без рамок, заголовка і підказки внизу).
+pub fn get_picker_visible_rows(picker_rect: Rect) -> usize {
+    picker_rect.height.saturating_sub(PICKER_LIST_ROW + 4) as usize
+}
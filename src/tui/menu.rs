@@ -43,5 +43,5 @@ pub trait MenuState {
     fn get_top_header(&self) -> Vec<Line<'_>> { vec![] }
     fn get_tools(&self) -> Vec<MenuItem>;
     fn get_info_section(&self) -> Vec<Line<'_>>;
-    fn get_logs(&self) -> String;
+    fn get_logs(&self) -> Vec<Line<'_>>;
 }
\ No newline at end of file
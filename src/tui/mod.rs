@@ -5,4 +5,6 @@ pub mod draw;
 pub mod input;
 pub mod menu;  // <-- Нове
 pub mod utils; // <-- Нове
-pub mod layout;
\ No newline at end of file
+pub mod layout;
+pub mod overlay;
+pub mod form;
\ No newline at end of file
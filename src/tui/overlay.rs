@@ -0,0 +1,33 @@
+// src/tui/overlay.rs
+
+use std::collections::HashMap;
+use ratatui::style::Color;
+
+use crate::map::position::MapPosition;
+
+/// Підсвітка клітинок поверх карти (фон тайла). Будується заново кожен кадр.
+#[derive(Debug, Clone, Default)]
+pub struct MapOverlay {
+    highlights: HashMap<MapPosition, Color>,
+}
+
+impl MapOverlay {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Фарбує клітинку; пізніший виклик перекриває попередній.
+    pub fn highlight(&mut self, pos: MapPosition, color: Color) {
+        self.highlights.insert(pos, color);
+    }
+
+    pub fn highlight_all<I: IntoIterator<Item = MapPosition>>(&mut self, positions: I, color: Color) {
+        for pos in positions {
+            self.highlight(pos, color);
+        }
+    }
+
+    pub fn background(&self, pos: MapPosition) -> Option<Color> {
+        self.highlights.get(&pos).copied()
+    }
+}
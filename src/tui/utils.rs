@@ -1,4 +1,6 @@
 use ratatui::style::Color;
+use crate::specials::powerup::PowerupType;
+use crate::specials::status::StatusKind;
 
 pub fn get_team_color(team: u32) -> Color {
     match team {
@@ -14,4 +16,24 @@ pub fn get_team_color(team: u32) -> Color {
             Color::Rgb(r, g, b)
         }
     }
-}
\ No newline at end of file
+}
+
+pub fn get_powerup_color(powerup: PowerupType) -> Color {
+    match powerup {
+        PowerupType::HealingPotion => Color::LightGreen,
+        PowerupType::SpeedBoost => Color::LightBlue,
+        PowerupType::WallBreaker => Color::Magenta,
+        PowerupType::None => Color::DarkGray,
+    }
+}
+
+pub fn get_status_color(status: StatusKind) -> Color {
+    match status {
+        StatusKind::Stun => Color::Yellow,
+        StatusKind::Poison => Color::Green,
+        StatusKind::Regeneration => Color::LightGreen,
+        StatusKind::Haste => Color::LightBlue,
+        StatusKind::Shield => Color::Cyan,
+        StatusKind::Rooted => Color::Rgb(160, 110, 60),
+    }
+}
@@ -0,0 +1,111 @@
+// tests/action_mask.rs
+
+use game::map::position::MapPosition;
+use game::rl::ActionSpace;
+use game::specials::entity::EntityID;
+use game::specials::{PowerupType, StatusEffect, StatusKind};
+use game::state::modes::game::{EngineCommand, GameEngine};
+use game::state::WorldState;
+
+fn pos(x: i32, y: i32) -> MapPosition {
+    MapPosition::new(x, y)
+}
+
+/// Мала карта 7x7: стіна, паверап, дві команди; серед юнітів є застанений і rooted.
+/// `end_turns` — скільки разів передати хід перед перевіркою.
+fn fixture(end_turns: usize) -> GameEngine {
+    let mut world = WorldState::with_size(7, 7);
+    for y in 1..4 {
+        world.build_wall(pos(3, y));
+    }
+    world.place_powerup(pos(1, 2), PowerupType::SpeedBoost);
+
+    let units = [
+        (pos(1, 1), 1, 6),
+        (pos(1, 4), 1, 6),
+        (pos(5, 5), 1, 6),
+        (pos(2, 1), 2, 4),
+        (pos(4, 2), 2, 4),
+        (pos(5, 6), 2, 4),
+    ];
+    for (p, team, energy) in units {
+        world.spawn_entity(p, 'U', "Unit".into(), 30, energy, 5, 1, team, false).unwrap();
+    }
+
+    let mut engine = GameEngine::new(world);
+    engine.apply_status(1, StatusEffect::new(StatusKind::Stun, 2, 0));
+    engine.apply_status(2, StatusEffect::new(StatusKind::Rooted, 2, 0));
+    engine.apply_status(4, StatusEffect::new(StatusKind::Rooted, 2, 0));
+    for _ in 0..end_turns {
+        engine.apply(EngineCommand::EndTurn).unwrap();
+    }
+    engine
+}
+
+fn unit_ids(engine: &GameEngine) -> Vec<EntityID> {
+    engine.world().entities.iter().map(|e| e.id()).collect()
+}
+
+/// Чи прийме рушій дію `index` для юніта (на свіжій копії фікстури).
+fn accepted(end_turns: usize, space: &ActionSpace, entity: EntityID, index: usize) -> bool {
+    let mut engine = fixture(end_turns);
+    space.decode(&engine, entity, index)
+        .and_then(|cmd| engine.apply(cmd))
+        .is_ok()
+}
+
+fn check_mask_matches_engine(end_turns: usize) {
+    let space = ActionSpace::new(3);
+    let engine = fixture(end_turns);
+
+    for entity in unit_ids(&engine) {
+        let mask = space.mask(&engine, entity);
+        assert_eq!(mask.len(), space.size());
+
+        let is_current = engine.world().get_entity(entity).unwrap().team() == engine.current_team();
+        if !is_current {
+            assert!(mask.iter().all(|&m| !m), "unit {} is not on the current team", entity);
+        }
+
+        for (index, &legal) in mask.iter().enumerate() {
+            // Кінець ходу не прив'язаний до юніта: рушій приймає його завжди, маска — лише для поточної команди
+            if index == 0 && !is_current {
+                continue;
+            }
+            assert_eq!(
+                legal,
+                accepted(end_turns, &space, entity, index),
+                "unit {}, action {} ({:?})",
+                entity, index, space.decode(&engine, entity, index),
+            );
+        }
+    }
+}
+
+#[test]
+fn mask_matches_engine_on_first_turn() {
+    check_mask_matches_engine(0);
+}
+
+#[test]
+fn mask_matches_engine_on_second_team_turn() {
+    check_mask_matches_engine(1);
+}
+
+#[test]
+fn fixture_covers_moves_attacks_and_blocked_units() {
+    let space = ActionSpace::new(3);
+    let engine = fixture(0);
+    let n = space.offsets().len();
+
+    let free = space.mask(&engine, 0);
+    assert!(free[1..=n].iter().any(|&m| m), "free unit can move");
+    assert!(free[1 + n..].iter().any(|&m| m), "free unit can attack its neighbour");
+
+    let stunned = space.mask(&engine, 1);
+    assert!(stunned[0] && stunned[1..].iter().all(|&m| !m));
+
+    let rooted = space.mask(&engine, 2);
+    assert!(rooted[1..=n].iter().all(|&m| !m));
+    assert!(rooted[1 + n..].iter().any(|&m| m), "rooted unit can still attack");
+}
@@ -0,0 +1,213 @@
+// tests/editor_history.rs
+
+use game::map::position::MapPosition;
+use game::map::tile::TileType;
+use game::specials::entity::EntityID;
+use game::state::modes::editor::entity_spec::EntitySpec;
+use game::state::modes::editor::history::{Edit, EditHistory, PendingEdit, CELL_BUDGET, HISTORY_LIMIT};
+use game::state::WorldState;
+
+fn pos(x: i32, y: i32) -> MapPosition {
+    MapPosition::new(x, y)
+}
+
+/// Карта 6x6 з трьома юнітами в рядку y = 1.
+fn world_with_units() -> (WorldState, Vec<EntityID>) {
+    let mut world = WorldState::with_size(6, 6);
+    let ids = (0..3)
+        .map(|x| world.spawn_entity(pos(x + 1, 1), 'U', format!("u{}", x), 10, 10, 1, 1, 1, false).unwrap())
+        .collect();
+    (world, ids)
+}
+
+/// Виконує `op` над клітинками `cells` так само, як редактор: знімки "до", зміна, дифф.
+fn record<F: FnOnce(&mut WorldState)>(world: &mut WorldState, cells: &[MapPosition], op: F) -> Edit {
+    let mut pending = PendingEdit::default();
+    for &p in cells {
+        pending.touch(world, p);
+    }
+    op(world);
+    pending.finish("test".to_string(), world)
+}
+
+fn undo(history: &mut EditHistory, world: &mut WorldState, spec: &mut EntitySpec) -> bool {
+    match history.undo() {
+        Some(edit) => { edit.revert(world, spec); true }
+        None => false,
+    }
+}
+
+fn redo(history: &mut EditHistory, world: &mut WorldState, spec: &mut EntitySpec) -> bool {
+    match history.redo() {
+        Some(edit) => { edit.apply(world, spec); true }
+        None => false,
+    }
+}
+
+fn tile_type(world: &WorldState, p: MapPosition) -> TileType {
+    world.map.get_tile(p).unwrap().tile_type()
+}
+
+#[test]
+fn tile_edit_round_trip() {
+    let mut world = WorldState::with_size(5, 5);
+    let mut spec = EntitySpec::default();
+    let mut history = EditHistory::new();
+
+    let cells = [pos(1, 1), pos(2, 1), pos(3, 1)];
+    let edit = record(&mut world, &cells, |w| {
+        for &p in &cells {
+            w.build_wall(p);
+        }
+    });
+    history.push(edit);
+
+    assert!(undo(&mut history, &mut world, &mut spec));
+    assert!(cells.iter().all(|&p| tile_type(&world, p) == TileType::WalkableGeneric));
+
+    assert!(redo(&mut history, &mut world, &mut spec));
+    assert!(cells.iter().all(|&p| tile_type(&world, p) == TileType::Wall));
+    assert!(!redo(&mut history, &mut world, &mut spec));
+}
+
+#[test]
+fn unchanged_cells_are_not_recorded() {
+    let mut world = WorldState::with_size(5, 5);
+    let mut history = EditHistory::new();
+
+    history.push(record(&mut world, &[pos(0, 0)], |w| w.build_floor(pos(0, 0))));
+    assert!(!history.can_undo());
+}
+
+#[test]
+fn entity_spawn_round_trip() {
+    let (mut world, ids) = world_with_units();
+    let mut spec = EntitySpec::default();
+    let mut history = EditHistory::new();
+
+    let target = pos(4, 4);
+    let mut new_id = 0;
+    history.push(record(&mut world, &[target], |w| {
+        let id = w.next_id();
+        new_id = w.add_entity(spec.to_entity(id, target));
+    }));
+
+    assert!(undo(&mut history, &mut world, &mut spec));
+    assert!(world.get_entity(new_id).is_none());
+    assert_eq!(world.get_entity_id_at(target), None);
+    assert_eq!(world.entity_order(), ids);
+
+    assert!(redo(&mut history, &mut world, &mut spec));
+    assert_eq!(world.get_entity_id_at(target), Some(new_id));
+    assert_eq!(world.entity_order().last(), Some(&new_id));
+}
+
+#[test]
+fn entity_removal_undo_keeps_original_order() {
+    let (mut world, ids) = world_with_units();
+    let mut spec = EntitySpec::default();
+    let mut history = EditHistory::new();
+
+    // Прибираємо першого і середнього одним кроком (як прямокутник Floor)
+    let cells = [pos(1, 1), pos(2, 1)];
+    history.push(record(&mut world, &cells, |w| {
+        for &p in &cells {
+            w.build_floor(p);
+        }
+    }));
+    assert_eq!(world.entity_order(), vec![ids[2]]);
+
+    assert!(undo(&mut history, &mut world, &mut spec));
+    assert_eq!(world.entity_order(), ids);
+    assert_eq!(world.get_entity_id_at(pos(1, 1)), Some(ids[0]));
+    assert_eq!(world.get_entity_id_at(pos(2, 1)), Some(ids[1]));
+
+    assert!(redo(&mut history, &mut world, &mut spec));
+    assert_eq!(world.entity_order(), vec![ids[2]]);
+    assert_eq!(world.get_entity_id_at(pos(1, 1)), None);
+}
+
+#[test]
+fn in_place_unit_edit_undo_keeps_original_order() {
+    let (mut world, ids) = world_with_units();
+    let mut spec = EntitySpec::default();
+    let mut history = EditHistory::new();
+
+    let first = pos(1, 1);
+    history.push(record(&mut world, &[first], |w| {
+        w.get_entity_mut(ids[0]).unwrap().set_damage(9);
+    }));
+
+    assert!(undo(&mut history, &mut world, &mut spec));
+    assert_eq!(world.get_entity(ids[0]).unwrap().damage(), 1);
+    assert_eq!(world.entity_order(), ids);
+
+    assert!(redo(&mut history, &mut world, &mut spec));
+    assert_eq!(world.get_entity(ids[0]).unwrap().damage(), 9);
+    assert_eq!(world.entity_order(), ids);
+}
+
+#[test]
+fn spec_edit_round_trip() {
+    let mut world = WorldState::with_size(5, 5);
+    let mut spec = EntitySpec::default();
+    let mut history = EditHistory::new();
+
+    let before = spec.clone();
+    spec.hp = 99;
+    spec.name = Some("Scout".to_string());
+    history.push(Edit::spec("spec".to_string(), before.clone(), spec.clone()));
+
+    assert!(undo(&mut history, &mut world, &mut spec));
+    assert_eq!(spec, before);
+
+    assert!(redo(&mut history, &mut world, &mut spec));
+    assert_eq!(spec.hp, 99);
+    assert_eq!(spec.name(), "Scout");
+}
+
+#[test]
+fn history_limit_drops_oldest_steps() {
+    let mut world = WorldState::with_size(5, 5);
+    let mut spec = EntitySpec::default();
+    let mut history = EditHistory::new();
+
+    // Стіна/підлога по черзі на одній клітинці — кожен крок реально щось змінює
+    for i in 0..HISTORY_LIMIT + 5 {
+        history.push(record(&mut world, &[pos(0, 0)], |w| {
+            if i % 2 == 0 { w.build_wall(pos(0, 0)); } else { w.build_floor(pos(0, 0)); }
+        }));
+    }
+
+    let mut undone = 0;
+    while undo(&mut history, &mut world, &mut spec) {
+        undone += 1;
+    }
+    assert_eq!(undone, HISTORY_LIMIT);
+}
+
+#[test]
+fn cell_budget_drops_oldest_steps() {
+    let side = 120;
+    let mut world = WorldState::with_size(side, side);
+    let mut spec = EntitySpec::default();
+    let mut history = EditHistory::new();
+
+    // Кожен крок більший за половину бюджету, тож двом поруч не вміститись
+    let per_edit = CELL_BUDGET / 2 + 1;
+    let cells: Vec<MapPosition> = (0..per_edit as i32).map(|i| pos(i % side, i / side)).collect();
+    assert!(per_edit <= (side * side) as usize);
+
+    for i in 0..3 {
+        history.push(record(&mut world, &cells, |w| {
+            for &p in &cells {
+                if i % 2 == 0 { w.build_wall(p); } else { w.build_floor(p); }
+            }
+        }));
+    }
+
+    assert!(undo(&mut history, &mut world, &mut spec));
+    assert!(!undo(&mut history, &mut world, &mut spec));
+    // Лишився тільки останній крок (стіни): після відкату — крок 2 (підлога)
+    assert_eq!(tile_type(&world, cells[0]), TileType::WalkableGeneric);
+}
@@ -0,0 +1,101 @@
+// tests/movement.rs
+
+use game::map::position::MapPosition;
+use game::specials::entity::EntityID;
+use game::specials::PowerupType;
+use game::state::modes::game::{EngineCommand, EngineError, GameEngine, GameEvent};
+use game::state::WorldState;
+
+fn pos(x: i32, y: i32) -> MapPosition {
+    MapPosition::new(x, y)
+}
+
+/// Карта 5x5 з юнітом команди 1 у (1, 1) і ворогом у кутку (щоб гра не скінчилась).
+/// `walls` — стіни, що будуються перед спавном.
+fn setup(walls: &[MapPosition], energy: u32) -> (GameEngine, EntityID) {
+    let mut world = WorldState::with_size(5, 5);
+    for &w in walls {
+        world.build_wall(w);
+    }
+    let id = world.spawn_entity(pos(1, 1), '@', "Player".into(), 50, energy, 5, 1, 1, false).unwrap();
+    world.spawn_entity(pos(4, 0), 'E', "Enemy".into(), 50, 10, 5, 1, 2, false).unwrap();
+    (GameEngine::new(world), id)
+}
+
+fn move_to(engine: &mut GameEngine, id: EntityID, to: MapPosition) -> Result<Vec<GameEvent>, EngineError> {
+    engine.apply(EngineCommand::Move { entity: id, to })
+}
+
+fn position(engine: &GameEngine, id: EntityID) -> MapPosition {
+    engine.world().get_entity(id).unwrap().position()
+}
+
+fn energy(engine: &GameEngine, id: EntityID) -> u32 {
+    engine.world().get_entity(id).unwrap().energy()
+}
+
+/// Стіна на x = 2 від y = 0 до y = 3: з (1, 1) до (3, 1) — лише в обхід через y = 4.
+fn detour_walls() -> Vec<MapPosition> {
+    (0..4).map(|y| pos(2, y)).collect()
+}
+
+#[test]
+fn walls_block_movement() {
+    let full_wall: Vec<MapPosition> = (0..5).map(|y| pos(2, y)).collect();
+    let (mut engine, id) = setup(&full_wall, 20);
+
+    let err = move_to(&mut engine, id, pos(3, 1)).unwrap_err();
+    assert_eq!(err, EngineError::NoPath { from: pos(1, 1), to: pos(3, 1) });
+
+    let err = move_to(&mut engine, id, pos(2, 1)).unwrap_err();
+    assert!(matches!(err, EngineError::PositionBlocked { pos: p, .. } if p == pos(2, 1)));
+
+    assert_eq!(position(&engine, id), pos(1, 1));
+    assert_eq!(energy(&engine, id), 20);
+}
+
+#[test]
+fn move_costs_the_real_path_length() {
+    let (mut engine, id) = setup(&detour_walls(), 20);
+
+    let events = move_to(&mut engine, id, pos(3, 1)).unwrap();
+    let path = events.iter().find_map(|e| match e {
+        GameEvent::EntityMoved { path, .. } => Some(path.clone()),
+        _ => None,
+    }).unwrap();
+
+    // Манхеттенська відстань 2, але шлях в обхід стіни — 8 кроків
+    assert_eq!(path.len(), 8);
+    assert_eq!(path.last(), Some(&pos(3, 1)));
+    assert!(path.iter().all(|p| p.x() != 2 || p.y() == 4));
+    assert_eq!(position(&engine, id), pos(3, 1));
+    assert_eq!(energy(&engine, id), 20 - 8);
+}
+
+#[test]
+fn path_longer_than_energy_is_rejected() {
+    let (mut engine, id) = setup(&detour_walls(), 5);
+
+    let err = move_to(&mut engine, id, pos(3, 1)).unwrap_err();
+    assert_eq!(err, EngineError::NotEnoughEnergyForDistance { distance: 8, required: 8, available: 5 });
+
+    assert_eq!(position(&engine, id), pos(1, 1));
+    assert_eq!(energy(&engine, id), 5);
+    assert!(engine.drain_events().is_empty());
+}
+
+#[test]
+fn traversed_tiles_trigger_powerups() {
+    let (mut engine, id) = setup(&[], 20);
+    engine.world_mut().place_powerup(pos(1, 2), PowerupType::WallBreaker);
+    engine.world_mut().place_powerup(pos(0, 3), PowerupType::HealingPotion);
+
+    let events = move_to(&mut engine, id, pos(1, 4)).unwrap();
+
+    assert!(events.contains(&GameEvent::PowerupPicked { entity: id, powerup: PowerupType::WallBreaker, pos: pos(1, 2) }));
+    assert_eq!(engine.world().get_entity(id).unwrap().wall_breaker_charges(), 1);
+    assert_eq!(engine.world().map.get_tile(pos(1, 2)).unwrap().powerup(), PowerupType::None);
+
+    // Клітинка поза шляхом свій паверап зберігає
+    assert_eq!(engine.world().map.get_tile(pos(0, 3)).unwrap().powerup(), PowerupType::HealingPotion);
+}
@@ -0,0 +1,99 @@
+// tests/save_format.rs
+
+use std::path::PathBuf;
+
+use serde_json::{json, Value};
+
+use game::specials::{SkillKind, StatusKind};
+use game::state::save_format::{self, SAVE_VERSION, VERSION_KEY};
+use game::state::WorldState;
+
+fn committed_map() -> String {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("map.json").to_string_lossy().into_owned()
+}
+
+fn temp_path(name: &str) -> String {
+    std::env::temp_dir()
+        .join(format!("telos_{}_{}.json", name, std::process::id()))
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Мінімальний документ старого формату (без версії, з `stunned_for_turns`).
+fn legacy_document(stunned: u32) -> Value {
+    let mut tiles = vec![json!({"tile_type": "WalkableGeneric", "symbol": ".", "entity_id": null, "powerup": "None"}); 4];
+    tiles[0]["entity_id"] = json!(0);
+    json!({
+        "map": {"width": 2, "height": 2, "tiles": tiles},
+        "entities": [{
+            "id": 0, "symbol": "@", "name": "Player", "team": 1,
+            "max_health": 50, "health": 50, "max_energy": 20, "energy": 20,
+            "damage": 5, "attack_range": 2, "position": [0, 0],
+            "stunned_for_turns": stunned, "is_selected": false, "is_ai": false
+        }],
+        "next_entity_id": 1,
+        "current_team_turn": 1
+    })
+}
+
+#[test]
+fn loads_committed_map() {
+    let world = WorldState::load(&committed_map()).expect("map.json should load");
+
+    assert_eq!((world.map.width(), world.map.height()), (100, 25));
+    assert!(!world.entities.is_empty());
+    assert!(world.next_entity_id as usize >= world.entities.len());
+    for entity in &world.entities {
+        assert!(entity.statuses().is_empty());
+        assert_eq!(entity.wall_breaker_charges(), 0);
+        assert_eq!(entity.skills().len(), SkillKind::ALL.len());
+    }
+}
+
+#[test]
+fn committed_map_is_legacy_and_migrates_to_current_version() {
+    let text = std::fs::read_to_string(committed_map()).unwrap();
+    let doc: Value = serde_json::from_str(&text).unwrap();
+    assert_eq!(save_format::document_version(&doc).unwrap(), 0);
+
+    let migrated = save_format::migrate(doc).unwrap();
+    assert_eq!(migrated[VERSION_KEY], json!(SAVE_VERSION));
+    assert!(migrated["entities"][0].get("stunned_for_turns").is_none());
+}
+
+#[test]
+fn saved_files_carry_version_and_round_trip() {
+    let world = WorldState::load(&committed_map()).unwrap();
+    let path = temp_path("round_trip");
+    world.save(&path).unwrap();
+
+    let raw: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(raw[VERSION_KEY], json!(SAVE_VERSION));
+
+    let reloaded = WorldState::load(&path).unwrap();
+    std::fs::remove_file(&path).ok();
+    assert_eq!(serde_json::to_value(&reloaded).unwrap(), serde_json::to_value(&world).unwrap());
+}
+
+#[test]
+fn legacy_stun_becomes_status() {
+    let world = save_format::from_document(legacy_document(2)).unwrap();
+    let stun = world.entities[0].status(StatusKind::Stun).expect("stun should migrate");
+    assert_eq!(stun.turns_left(), 2);
+
+    let world = save_format::from_document(legacy_document(0)).unwrap();
+    assert!(world.entities[0].statuses().is_empty());
+}
+
+#[test]
+fn rejects_newer_versions() {
+    let mut doc = legacy_document(0);
+    doc[VERSION_KEY] = json!(SAVE_VERSION + 1);
+    let err = save_format::from_document(doc).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn rejects_non_object_documents() {
+    assert!(save_format::from_document(json!([1, 2, 3])).is_err());
+}